@@ -0,0 +1,38 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use pallet_chainlink_feed::median;
+use std::fmt::Display;
+
+fn check_bounds<T: Copy + Ord + Display>(values: &[T], result: T) {
+	let min = *values.iter().min().unwrap();
+	let max = *values.iter().max().unwrap();
+	assert!(result >= min, "median {} below minimum {}", result, min);
+	assert!(result <= max, "median {} above maximum {}", result, max);
+	if values.len() == 1 {
+		assert_eq!(result, values[0]);
+	}
+}
+
+fuzz_target!(|input: (Vec<u32>, Vec<i64>)| {
+	let (unsigned, signed) = input;
+	if unsigned.is_empty() {
+		assert_eq!(median(&mut Vec::<u32>::new()), 0);
+	} else {
+		let result = median(&mut unsigned.clone());
+		check_bounds(&unsigned, result);
+	}
+
+	if signed.is_empty() {
+		assert_eq!(median(&mut Vec::<i64>::new()), 0);
+	} else {
+		let mut sorted = signed.clone();
+		let result = median(&mut sorted);
+		check_bounds(&signed, result);
+		if sorted.len() % 2 == 0 {
+			// compare with the mean computed without overflow, rounded towards zero
+			let mid = sorted.len() / 2;
+			let expected = (sorted[mid - 1] as i128 + sorted[mid] as i128) / 2;
+			assert_eq!(result as i128, expected);
+		}
+	}
+});
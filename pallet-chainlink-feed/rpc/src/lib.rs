@@ -0,0 +1,217 @@
+//! JSON-RPC interface for the chainlink feed pallet
+//!
+//! Serves feed data in the JSON friendly types of `pallet_chainlink_feed::rpc`, so DApps
+//! do not have to query and decode the raw storage. The data is read via the
+//! `ChainlinkFeedApi` runtime API at the given block (or the best block).
+use std::{
+	convert::{TryFrom, TryInto},
+	fmt::Debug,
+	marker::PhantomData,
+	sync::Arc,
+};
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use pallet_chainlink_feed::{
+	rpc::{FeedConfigRpc, RoundDataRpc},
+	BalanceOf, Config, FeedSnapshot, RoundDataOf, RoundId, RoundOf,
+};
+pub use pallet_chainlink_feed_runtime_api::ChainlinkFeedApi as ChainlinkFeedRuntimeApi;
+use serde::de::DeserializeOwned;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[rpc]
+pub trait ChainlinkFeedApi<BlockHash, FeedId> {
+	/// Return the latest answered round of the feed.
+	///
+	/// `None` if the feed does not exist or has not been answered, yet.
+	#[rpc(name = "chainlink_latestRoundData")]
+	fn latest_round_data(
+		&self,
+		feed_id: FeedId,
+		at: Option<BlockHash>,
+	) -> Result<Option<RoundDataRpc>>;
+
+	/// Return the data of the given round.
+	///
+	/// `None` if the round is not in storage, precedes the first valid round of the
+	/// feed or has not been answered, yet.
+	#[rpc(name = "chainlink_roundData")]
+	fn round_data(
+		&self,
+		feed_id: FeedId,
+		round_id: RoundId,
+		at: Option<BlockHash>,
+	) -> Result<Option<RoundDataRpc>>;
+
+	/// Return the config of the feed.
+	#[rpc(name = "chainlink_feedConfig")]
+	fn feed_config(&self, feed_id: FeedId, at: Option<BlockHash>) -> Result<Option<FeedConfigRpc>>;
+
+	/// Return the configs of up to `count` feeds, starting at the feed id `start`.
+	#[rpc(name = "chainlink_feedList")]
+	fn feed_list(
+		&self,
+		start: FeedId,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<FeedConfigRpc>>;
+}
+
+/// Error code for failed runtime API calls.
+const RUNTIME_ERROR: i64 = 1;
+/// Error code for data that does not fit into the RPC types.
+const CONVERSION_ERROR: i64 = 2;
+
+fn runtime_error(e: impl Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: "Unable to query the chainlink feed.".into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+fn conversion_error(e: impl Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(CONVERSION_ERROR),
+		message: "Unable to convert the chainlink feed data.".into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+/// Implements [`ChainlinkFeedApi`] for the runtime `T`.
+pub struct ChainlinkFeed<C, Block, T> {
+	client: Arc<C>,
+	_marker: PhantomData<(Block, T)>,
+}
+
+impl<C, Block, T> ChainlinkFeed<C, Block, T> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<C, Block, T> ChainlinkFeed<C, Block, T>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	fn block_id(&self, at: Option<Block::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+/// Convert an answered round, `None` for unanswered ones.
+fn round_to_rpc<T: Config>(round_id: RoundId, round: RoundOf<T>) -> Result<Option<RoundDataRpc>>
+where
+	T::BlockNumber: TryInto<u64>,
+	T::Value: TryInto<u128>,
+{
+	match RoundDataOf::<T>::try_from((round_id, round)) {
+		Ok(data) => RoundDataRpc::try_from(data)
+			.map(Some)
+			.map_err(conversion_error),
+		Err(_) => Ok(None),
+	}
+}
+
+impl<C, Block, T> ChainlinkFeedApi<Block::Hash, T::FeedId> for ChainlinkFeed<C, Block, T>
+where
+	Block: BlockT,
+	T: Config + Send + Sync,
+	T::FeedId: TryInto<u64> + DeserializeOwned,
+	T::BlockNumber: TryInto<u64>,
+	T::Value: TryInto<u128>,
+	BalanceOf<T>: TryInto<u128>,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ChainlinkFeedRuntimeApi<
+		Block,
+		T::AccountId,
+		BalanceOf<T>,
+		T::FeedId,
+		T::BlockNumber,
+		T::Value,
+		T::Hash,
+		FeedSnapshot<T>,
+	>,
+{
+	fn latest_round_data(
+		&self,
+		feed_id: T::FeedId,
+		at: Option<Block::Hash>,
+	) -> Result<Option<RoundDataRpc>> {
+		let snapshot = self
+			.client
+			.runtime_api()
+			.feed_snapshot(&self.block_id(at), feed_id)
+			.map_err(runtime_error)?;
+		match snapshot {
+			// the seed round does not contain an answer
+			Some(snapshot) if snapshot.config.first_valid_round.is_some() => {
+				round_to_rpc::<T>(snapshot.config.latest_round, snapshot.latest_round)
+			}
+			_ => Ok(None),
+		}
+	}
+
+	fn round_data(
+		&self,
+		feed_id: T::FeedId,
+		round_id: RoundId,
+		at: Option<Block::Hash>,
+	) -> Result<Option<RoundDataRpc>> {
+		let at = self.block_id(at);
+		let api = self.client.runtime_api();
+		let snapshot = api.feed_snapshot(&at, feed_id).map_err(runtime_error)?;
+		match snapshot.and_then(|s| s.config.first_valid_round) {
+			// the seed round and rounds before the first valid one do not contain an answer
+			Some(first_valid_round) if round_id >= first_valid_round => {}
+			_ => return Ok(None),
+		}
+		let rounds = api
+			.paginated_rounds(&at, feed_id, round_id, 1)
+			.map_err(runtime_error)?;
+		match rounds.into_iter().next() {
+			Some((round_id, round)) => round_to_rpc::<T>(round_id, round),
+			None => Ok(None),
+		}
+	}
+
+	fn feed_config(
+		&self,
+		feed_id: T::FeedId,
+		at: Option<Block::Hash>,
+	) -> Result<Option<FeedConfigRpc>> {
+		let snapshot = self
+			.client
+			.runtime_api()
+			.feed_snapshot(&self.block_id(at), feed_id)
+			.map_err(runtime_error)?;
+		snapshot
+			.map(|s| FeedConfigRpc::try_from(s.config))
+			.transpose()
+			.map_err(conversion_error)
+	}
+
+	fn feed_list(
+		&self,
+		start: T::FeedId,
+		count: u32,
+		at: Option<Block::Hash>,
+	) -> Result<Vec<FeedConfigRpc>> {
+		let snapshots = self
+			.client
+			.runtime_api()
+			.paginated_snapshots(&self.block_id(at), start, count)
+			.map_err(runtime_error)?;
+		snapshots
+			.into_iter()
+			.map(|s| FeedConfigRpc::try_from(s.config).map_err(conversion_error))
+			.collect()
+	}
+}
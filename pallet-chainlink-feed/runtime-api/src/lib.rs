@@ -0,0 +1,73 @@
+//! Runtime API definition for the chainlink feed pallet
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_chainlink_feed::{FeedSummary, OracleStatus, Requester, Round, RoundId};
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// Read access to the feeds that is not covered by plain storage queries.
+	///
+	/// `FeedSnapshot` is the pallet's `FeedSnapshot` type for the runtime.
+	pub trait ChainlinkFeedApi<AccountId, Balance, FeedId, BlockNumber, Value, Hash, FeedSnapshot> where
+		AccountId: Codec,
+		Balance: Codec,
+		FeedId: Codec,
+		BlockNumber: Codec,
+		Value: Codec,
+		Hash: Codec,
+		FeedSnapshot: Codec,
+	{
+		/// Return up to `count` stored rounds of the feed, starting at `start`.
+		///
+		/// `count` is clamped to the pallet's `MAX_PAGE_SIZE`.
+		fn paginated_rounds(
+			feed_id: FeedId,
+			start: RoundId,
+			count: u32,
+		) -> Vec<(RoundId, Round<BlockNumber, Value>)>;
+
+		/// Return the current state of the feed.
+		fn feed_snapshot(feed_id: FeedId) -> Option<FeedSnapshot>;
+
+		/// Return the current state of up to `count` feeds, starting at `start`.
+		///
+		/// `count` is clamped to the pallet's `MAX_PAGE_SIZE`.
+		fn paginated_snapshots(start: FeedId, count: u32) -> Vec<FeedSnapshot>;
+
+		/// Return the summary of up to `count` feeds, starting at `start`.
+		///
+		/// `count` is clamped to the pallet's `MAX_PAGE_SIZE`.
+		fn all_feed_summaries(
+			start: FeedId,
+			count: u32,
+		) -> Vec<FeedSummary<FeedId, Hash, Value, BlockNumber>>;
+
+		/// Return the answer of the feed that was current at the given block.
+		///
+		/// `None` if the feed was not answered at that block or the round was pruned.
+		/// Walks back from the latest round, so keep `block` within the expected window.
+		fn feed_value_at_block(feed_id: FeedId, block: BlockNumber) -> Option<Value>;
+
+		/// Return all oracles of the feed together with their status.
+		fn oracle_statuses(
+			feed_id: FeedId,
+		) -> Vec<(AccountId, OracleStatus<BlockNumber, Value, Balance>)>;
+
+		/// Return the number of submissions the round has received.
+		fn round_submission_count(feed_id: FeedId, round_id: RoundId) -> Option<u32>;
+
+		/// Return the feeds the oracle is enabled on.
+		fn feeds_for_oracle(oracle: AccountId) -> Vec<FeedId>;
+
+		/// Return the sum of the withdrawable payments of all oracles of the admin.
+		fn oracle_earnings(admin: AccountId) -> Balance;
+
+		/// Return all oracles administered by the admin.
+		fn oracle_addresses_for_admin(admin: AccountId) -> Vec<AccountId>;
+
+		/// Return all requesters of the feed together with their permissions.
+		fn list_requesters(feed_id: FeedId) -> Vec<(AccountId, Requester)>;
+	}
+}
@@ -2,7 +2,7 @@ use super::*;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::traits::{Currency, Get};
 use frame_system::RawOrigin;
-use sp_runtime::traits::{AccountIdConversion, One, Zero};
+use sp_runtime::traits::{One, Zero};
 use sp_std::{fmt::Debug, vec, vec::Vec};
 
 use crate::Pallet as ChainlinkFeed;
@@ -35,6 +35,7 @@ benchmarks! {
 
 	create_feed {
 		let o in 1 .. T::OracleCountLimit::get();
+		let t in 0 .. T::MaxTags::get();
 
 		let caller: T::AccountId = whitelisted_caller();
 		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
@@ -42,6 +43,7 @@ benchmarks! {
 		let admin: T::AccountId = account("oracle_admin", 0, SEED);
 		let oracles: Vec<(T::AccountId, T::AccountId)> = (0..o).map(|n| (account("oracle", n, SEED), admin.clone())).collect();
 		let description = vec![1; T::StringLimit::get() as usize];
+		let tags: Vec<Vec<u8>> = (0..t).map(|n| vec![n as u8; T::TagLimit::get() as usize]).collect();
 	}: _(
 			RawOrigin::Signed(caller.clone()),
 			600u32.into(),
@@ -53,7 +55,9 @@ benchmarks! {
 			Zero::zero(),
 			oracles,
 			None,
-			None
+			None,
+			0,
+			tags
 		)
 	verify {
 		let feed: T::FeedId = Zero::zero();
@@ -79,6 +83,8 @@ benchmarks! {
 			vec![(oracle, admin)],
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let feed = Zero::zero();
 		let new_owner: T::AccountId = account("new_owner", 0, SEED);
@@ -106,6 +112,8 @@ benchmarks! {
 			vec![(oracle, admin)],
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let feed = Zero::zero();
 		let new_owner: T::AccountId = account("new_owner", 0, SEED);
@@ -136,6 +144,8 @@ benchmarks! {
 			vec![(oracle.clone(), admin)],
 			None,
 			None,
+			0,
+			vec![],
 		));
 
 		// ininitialize 25 rounds
@@ -153,6 +163,193 @@ benchmarks! {
 			assert_eq!(f.config.pruning_window, 26 - o);
 		}
 
+	prune_single_round {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			vec![(oracle.clone(), admin)],
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let feed: T::FeedId = Zero::zero();
+		for round in 1..4 {
+			assert_is_ok(ChainlinkFeed::<T>::submit(RawOrigin::Signed(oracle.clone()).into(), feed, round, 42u8.into()));
+		}
+		// shrink the window without pruning
+		Feeds::<T>::mutate(feed, |config| {
+			if let Some(config) = config.as_mut() {
+				config.pruning_window = One::one();
+			}
+		});
+		let round: RoundId = 1;
+	}: _(RawOrigin::Signed(caller.clone()), feed, round)
+	verify {
+		assert_eq!(ChainlinkFeed::<T>::round(feed, round), None);
+	}
+
+	set_deviation_alert {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			vec![(oracle, admin)],
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let feed = Zero::zero();
+		let percent: u8 = 10;
+	}: _(RawOrigin::Signed(caller.clone()), feed, percent)
+	verify {
+		assert_eq!(ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there").deviation_alert_percent, percent);
+	}
+
+	set_min_oracle_count {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			vec![(oracle, admin)],
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let feed = Zero::zero();
+		let min_oracle_count: u32 = 1;
+	}: _(RawOrigin::Signed(caller.clone()), feed, min_oracle_count)
+	verify {
+		assert_eq!(ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there").min_oracle_count, min_oracle_count);
+	}
+
+	set_tags {
+		let t in 0 .. T::MaxTags::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let description = vec![1; T::StringLimit::get() as usize];
+		// the existing tags have to be removed from the index
+		let old_tags: Vec<Vec<u8>> = (0..T::MaxTags::get()).map(|n| vec![n as u8; T::TagLimit::get() as usize]).collect();
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			vec![(oracle, admin)],
+			None,
+			None,
+			0,
+			old_tags,
+		));
+		let feed = Zero::zero();
+		let tags: Vec<Vec<u8>> = (0..t).map(|n| vec![n as u8 + 1; T::TagLimit::get() as usize]).collect();
+	}: _(RawOrigin::Signed(caller.clone()), feed, tags)
+	verify {
+		assert_eq!(ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there").tags.len() as u32, t);
+	}
+
+	deprecate_feed {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			vec![(oracle, admin)],
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let feed = Zero::zero();
+	}: _(RawOrigin::Signed(caller.clone()), feed)
+	verify {
+		assert!(ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there").deprecated);
+	}
+
+	undeprecate_feed {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			vec![(oracle, admin)],
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let feed = Zero::zero();
+		assert_is_ok(ChainlinkFeed::<T>::deprecate_feed(RawOrigin::Signed(caller.clone()).into(), feed));
+		// worst case: the owner is only checked after the pallet admin
+	}: _(RawOrigin::Signed(caller.clone()), feed)
+	verify {
+		assert!(!ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there").deprecated);
+	}
+
 	// The submit call opening a round is more expensive than a regular submission because of
 	// the round init code as well as the closing of previous rounds.
 	// It is most expensive in case it also directly closes the round.
@@ -178,6 +375,8 @@ benchmarks! {
 			oracles.clone(),
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let feed: T::FeedId = Zero::zero();
 		let prev_round: RoundId = 1;
@@ -192,7 +391,7 @@ benchmarks! {
 		let round: RoundId = 2;
 		assert_eq!(ChainlinkFeed::<T>::round(feed, round), None);
 		// make sure we hit the `Debt` storage item
-		let fund_account = T::PalletId::get().into_account();
+		let fund_account = ChainlinkFeed::<T>::fund_account_id();
 		T::Currency::make_free_balance_be(&fund_account, Zero::zero());
 	}: submit(
 			RawOrigin::Signed(oracle.clone()),
@@ -237,6 +436,8 @@ benchmarks! {
 			oracles.clone(),
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let feed: T::FeedId = Zero::zero();
 		let prev_round: RoundId = 1;
@@ -255,7 +456,7 @@ benchmarks! {
 		}
 		assert_eq!(ChainlinkFeed::<T>::round(feed, round), Some(Round::new(One::one())));
 		// make sure we hit the `Debt` storage item
-		let fund_account = T::PalletId::get().into_account();
+		let fund_account = ChainlinkFeed::<T>::fund_account_id();
 		T::Currency::make_free_balance_be(&fund_account, Zero::zero());
 	}: submit(
 			RawOrigin::Signed(oracle.clone()),
@@ -296,6 +497,8 @@ benchmarks! {
 			oracles.clone(),
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let oracles_before = oracles.into_iter().map(|(o, _a)| o).collect();
 		let feed: T::FeedId = Zero::zero();
@@ -329,6 +532,8 @@ benchmarks! {
 			oracles.clone(),
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let payment: BalanceOf<T> = 42u32.into();
 		let timeout: T::BlockNumber = 3u8.into();
@@ -342,10 +547,221 @@ benchmarks! {
 			timeout
 		)
 	verify {
-		let config = ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there");
-		assert_eq!(config.payment, payment);
-		assert_eq!(config.timeout, timeout);
+		let config = ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there");
+		assert_eq!(config.payment, payment);
+		assert_eq!(config.timeout, timeout);
+	}
+
+	set_payment_amount {
+		let o = 2;
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let oracles: Vec<(T::AccountId, T::AccountId)> = (0..o).map(|n| (account("oracle", n, SEED), admin.clone())).collect();
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			oracles,
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let payment: BalanceOf<T> = 42u32.into();
+		let feed: T::FeedId = Zero::zero();
+	}: _(RawOrigin::Signed(caller.clone()), feed, payment)
+	verify {
+		let config = ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there");
+		assert_eq!(config.payment, payment);
+	}
+
+	set_timeout {
+		let o = 2;
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let oracles: Vec<(T::AccountId, T::AccountId)> = (0..o).map(|n| (account("oracle", n, SEED), admin.clone())).collect();
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			oracles,
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let timeout: T::BlockNumber = 3u8.into();
+		let feed: T::FeedId = Zero::zero();
+	}: _(RawOrigin::Signed(caller.clone()), feed, timeout)
+	verify {
+		let config = ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there");
+		assert_eq!(config.timeout, timeout);
+	}
+
+	update_feed_config {
+		let o = 2;
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let oracles: Vec<(T::AccountId, T::AccountId)> = (0..o).map(|n| (account("oracle", n, SEED), admin.clone())).collect();
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			oracles.clone(),
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let payment: BalanceOf<T> = 42u32.into();
+		let timeout: T::BlockNumber = 3u8.into();
+		let value_bounds: (T::Value, T::Value) = (2u8.into(), 200u8.into());
+		let feed: T::FeedId = Zero::zero();
+		let updates = FeedConfigUpdates {
+			payment: Some(payment),
+			timeout: Some(timeout),
+			submission_value_bounds: Some(value_bounds),
+			submission_count_bounds: Some((1, oracles.len() as u32)),
+			restart_delay: Some(1),
+			description: Some(vec![2; T::StringLimit::get() as usize]),
+			deviation_alert_percent: Some(10),
+			treasury_percent: Some(10),
+			max_rounds_open: Some(5),
+			submission_deadline_offset: Some(Some(10u8.into())),
+		};
+	}: _(RawOrigin::Signed(caller.clone()), feed, updates)
+	verify {
+		let config = ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there");
+		assert_eq!(config.payment, payment);
+		assert_eq!(config.timeout, timeout);
+		assert_eq!(config.submission_value_bounds, value_bounds);
+	}
+
+	set_round_payment {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			vec![(oracle, admin)],
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let feed: T::FeedId = Zero::zero();
+		let round: RoundId = 1;
+		let payment: BalanceOf<T> = 42u32.into();
+	}: _(RawOrigin::Signed(caller.clone()), feed, round, payment)
+	verify {
+		assert_eq!(ChainlinkFeed::<T>::round_payment_override(feed, round), Some(payment));
+	}
+
+	close_round {
+		let o = T::OracleCountLimit::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let oracles: Vec<(T::AccountId, T::AccountId)> = (0..o).map(|n| (account("oracle", n, SEED), admin.clone())).collect();
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1,
+			5u8.into(),
+			description,
+			Zero::zero(),
+			oracles.clone(),
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let feed: T::FeedId = Zero::zero();
+		let round: RoundId = 1;
+		// all but one oracle submit so the round stays open with the most submissions
+		for (oracle, _admin) in oracles.iter().skip(1) {
+			assert_is_ok(ChainlinkFeed::<T>::submit(RawOrigin::Signed(oracle.clone()).into(), feed, round, 42u8.into()));
+		}
+	}: _(RawOrigin::Signed(caller.clone()), feed, round)
+	verify {
+		assert_eq!(ChainlinkFeed::<T>::round_details(feed, round), None);
+	}
+
+	close_timed_out_round {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let oracles: Vec<(T::AccountId, T::AccountId)> = (0..2).map(|n| (account("oracle", n, SEED), admin.clone())).collect();
+		let description = vec![1; T::StringLimit::get() as usize];
+		frame_system::Pallet::<T>::set_block_number(1u8.into());
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			One::one(),
+			(1u8.into(), 100u8.into()),
+			2,
+			5u8.into(),
+			description,
+			Zero::zero(),
+			oracles.clone(),
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let feed: T::FeedId = Zero::zero();
+		let round: RoundId = 1;
+		// a single submission does not answer the round
+		assert_is_ok(ChainlinkFeed::<T>::submit(RawOrigin::Signed(oracles[0].0.clone()).into(), feed, round, 42u8.into()));
+		frame_system::Pallet::<T>::set_block_number(3u8.into());
+		// fund the pallet so the reward is paid
+		let fund = ChainlinkFeed::<T>::fund_account_id();
+		let amount = T::MinimumReserve::get() * 10u32.into();
+		T::Currency::make_free_balance_be(&fund, amount);
+	}: _(RawOrigin::Signed(caller.clone()), feed, round)
+	verify {
+		assert_eq!(ChainlinkFeed::<T>::round_details(feed, round), None);
 	}
+
 	set_requester {
 		let caller: T::AccountId = whitelisted_caller();
 		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
@@ -365,10 +781,12 @@ benchmarks! {
 			vec![(oracle, admin)],
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let feed = Zero::zero();
 		let requester: T::AccountId = account("requester", 0, SEED);
-		let delay: RoundId = 3;
+		let delay: RoundId = T::MinRequesterDelay::get().max(3);
 	}: _(RawOrigin::Signed(caller.clone()), feed, requester.clone(), delay)
 	verify {
 		assert_eq!(ChainlinkFeed::<T>::requester(feed, requester).expect("feed should be there").delay, delay);
@@ -393,10 +811,12 @@ benchmarks! {
 			vec![(oracle, admin)],
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let feed = Zero::zero();
 		let requester: T::AccountId = account("requester", 0, SEED);
-		let delay: RoundId = 3;
+		let delay: RoundId = T::MinRequesterDelay::get().max(3);
 		assert_is_ok(ChainlinkFeed::<T>::set_requester(RawOrigin::Signed(caller.clone()).into(), feed, requester.clone(), delay));
 	}: _(RawOrigin::Signed(caller.clone()), feed, requester.clone())
 	verify {
@@ -423,6 +843,8 @@ benchmarks! {
 			oracles.clone(),
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let feed: T::FeedId = Zero::zero();
 		let round: RoundId = One::one();
@@ -437,7 +859,7 @@ benchmarks! {
 		let config = ChainlinkFeed::<T>::feed_config(feed).expect("config should be there");
 		assert_eq!(config.reporting_round, round);
 		let requester: T::AccountId = account("requester", 0, SEED);
-		let delay: RoundId = 3;
+		let delay: RoundId = T::MinRequesterDelay::get().max(3);
 		assert_is_ok(ChainlinkFeed::<T>::set_requester(RawOrigin::Signed(caller.clone()).into(), feed, requester.clone(), delay));
 	}: _(
 			RawOrigin::Signed(requester.clone()),
@@ -469,6 +891,8 @@ benchmarks! {
 			oracles.clone(),
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let feed: T::FeedId = Zero::zero();
 		let round: RoundId = One::one();
@@ -481,7 +905,7 @@ benchmarks! {
 			answer
 		));
 		let recipient: T::AccountId = account("recipient", 0, SEED);
-		let fund_account = T::PalletId::get().into_account();
+		let fund_account = ChainlinkFeed::<T>::fund_account_id();
 		T::Currency::make_free_balance_be(&fund_account, payment + payment);
 	}: _(
 		RawOrigin::Signed(admin.clone()),
@@ -493,6 +917,54 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(&recipient), payment);
 	}
 
+	withdraw_payment_all {
+		let o in 1 .. T::OracleCountLimit::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let oracles: Vec<(T::AccountId, T::AccountId)> = (0..o).map(|n| (account("oracle", n, SEED), admin.clone())).collect();
+		let payment: BalanceOf<T> = 600u32.into(); // ExistentialDeposit is 500
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			payment,
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1,
+			5u8.into(),
+			description,
+			Zero::zero(),
+			oracles.clone(),
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let fund_account = ChainlinkFeed::<T>::fund_account_id();
+		T::Currency::make_free_balance_be(&fund_account, payment * (o + 1).into());
+		let feed: T::FeedId = Zero::zero();
+		let round: RoundId = One::one();
+		let answer: T::Value = 5u8.into();
+		for (oracle, _admin) in oracles.iter() {
+			assert_is_ok(ChainlinkFeed::<T>::submit(
+				RawOrigin::Signed(oracle.clone()).into(),
+				feed,
+				round,
+				answer
+			));
+		}
+		let oracles: Vec<T::AccountId> = oracles.into_iter().map(|(o, _a)| o).collect();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+	}: _(
+		RawOrigin::Signed(admin.clone()),
+		oracles,
+		recipient.clone()
+	)
+	verify {
+		assert_eq!(T::Currency::free_balance(&recipient), payment * o.into());
+	}
+
 	transfer_admin {
 		let oracle: T::AccountId = account("oracle", 0, SEED);
 		let admin: T::AccountId = account("oracle_admin", 0, SEED);
@@ -500,6 +972,8 @@ benchmarks! {
 			withdrawable: Zero::zero(),
 			admin: admin.clone(),
 			pending_admin: None,
+			total_earned: Zero::zero(),
+			total_withdrawn: Zero::zero(),
 		});
 		let new_admin: T::AccountId = account("new_admin", 0, SEED);
 	}: _(
@@ -512,11 +986,39 @@ benchmarks! {
 			withdrawable: Zero::zero(),
 			admin: admin.clone(),
 			pending_admin: Some(new_admin.clone()),
+			total_earned: Zero::zero(),
+			total_withdrawn: Zero::zero(),
 		};
 		let meta = ChainlinkFeed::<T>::oracle(&oracle);
 		assert_eq!(meta, Some(expected_meta));
 	}
 
+	transfer_admin_batch {
+		let t in 1 .. T::OracleCountLimit::get();
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let new_admin: T::AccountId = account("new_admin", 0, SEED);
+		let transfers: Vec<(T::AccountId, T::AccountId)> = (0..t).map(|i| {
+			let oracle: T::AccountId = account("oracle", i, SEED);
+			Oracles::<T>::insert(&oracle, OracleMeta {
+				withdrawable: Zero::zero(),
+				admin: admin.clone(),
+				pending_admin: None,
+				total_earned: Zero::zero(),
+				total_withdrawn: Zero::zero(),
+			});
+			(oracle, new_admin.clone())
+		}).collect();
+	}: _(
+		RawOrigin::Signed(admin.clone()),
+		transfers.clone()
+	)
+	verify {
+		for (oracle, _) in transfers {
+			let meta = ChainlinkFeed::<T>::oracle(&oracle).expect("oracle should exist");
+			assert_eq!(meta.pending_admin, Some(new_admin.clone()));
+		}
+	}
+
 	accept_admin {
 		let oracle: T::AccountId = account("oracle", 0, SEED);
 		let admin: T::AccountId = account("oracle_admin", 0, SEED);
@@ -524,6 +1026,8 @@ benchmarks! {
 			withdrawable: Zero::zero(),
 			admin: admin.clone(),
 			pending_admin: None,
+			total_earned: Zero::zero(),
+			total_withdrawn: Zero::zero(),
 		});
 		let new_admin: T::AccountId = whitelisted_account::<T>("new_admin", 0);
 		assert_is_ok(ChainlinkFeed::<T>::transfer_admin(
@@ -540,6 +1044,8 @@ benchmarks! {
 			withdrawable: Zero::zero(),
 			admin: new_admin.clone(),
 			pending_admin: None,
+			total_earned: Zero::zero(),
+			total_withdrawn: Zero::zero(),
 		};
 		let meta = ChainlinkFeed::<T>::oracle(&oracle);
 		assert_eq!(meta, Some(expected_meta));
@@ -550,7 +1056,7 @@ benchmarks! {
 		whitelist_acc::<T>(&pallet_admin);
 		let payment: BalanceOf<T> = 600u32.into(); // ExistentialDeposit is 500
 		let recipient: T::AccountId = account("recipient", 0, SEED);
-		let fund_account = T::PalletId::get().into_account();
+		let fund_account = ChainlinkFeed::<T>::fund_account_id();
 		let multiplier = 1001u32.into();
 		T::Currency::make_free_balance_be(&fund_account, payment * multiplier);
 	}: _(
@@ -582,11 +1088,13 @@ benchmarks! {
 			vec![(oracle.clone(), admin)],
 			None,
 			None,
+			0,
+			vec![],
 		));
 		let feed = Zero::zero();
 		let answer: T::Value = 42u8.into();
 		let rounds: RoundId = 4;
-		let fund_account = T::PalletId::get().into_account();
+		let fund_account = ChainlinkFeed::<T>::fund_account_id();
 		T::Currency::make_free_balance_be(&fund_account, Zero::zero());
 		for round in 1..(rounds + 1) {
 			assert_is_ok(ChainlinkFeed::<T>::submit(RawOrigin::Signed(oracle.clone()).into(), feed, round, answer));
@@ -598,6 +1106,112 @@ benchmarks! {
 	verify {
 	}
 
+	prune_all_expired {
+		let f in 1 .. 10;
+		let r in 1 .. 25;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let description = vec![1; T::StringLimit::get() as usize];
+		for _ in 0..f {
+			assert_is_ok(ChainlinkFeed::<T>::create_feed(
+				RawOrigin::Signed(caller.clone()).into(),
+				600u32.into(),
+				Zero::zero(),
+				(1u8.into(), 100u8.into()),
+				1u8.into(),
+				5u8.into(),
+				description.clone(),
+				Zero::zero(),
+				vec![(oracle.clone(), admin.clone())],
+				None,
+				None,
+				0,
+				vec![],
+			));
+		}
+		// only the first feed has rounds outside the window
+		let feed: T::FeedId = Zero::zero();
+		for round in 1..(r + 2) {
+			assert_is_ok(ChainlinkFeed::<T>::submit(RawOrigin::Signed(oracle.clone()).into(), feed, round, 42u8.into()));
+		}
+		Feeds::<T>::translate::<FeedConfigOf<T>, _>(|_, mut config| {
+			config.pruning_window = One::one();
+			Some(config)
+		});
+	}: _(RawOrigin::Signed(pallet_admin.clone()), f, r)
+	verify {
+		let f = <Feed<T>>::read_only_from(feed).unwrap();
+		assert_eq!(f.config.next_round_to_prune, r + 1);
+	}
+
+	force_answer {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracles: Vec<(T::AccountId, T::AccountId)> = (0..2).map(|i| {
+			(account("oracle", i, SEED), account("oracle_admin", i, SEED))
+		}).collect();
+		let payment: BalanceOf<T> = 600u32.into();
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			payment,
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			2,
+			5u8.into(),
+			vec![1; T::StringLimit::get() as usize],
+			Zero::zero(),
+			oracles.clone(),
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let fund_account = ChainlinkFeed::<T>::fund_account_id();
+		T::Currency::make_free_balance_be(&fund_account, payment * 10u32.into());
+		let feed: T::FeedId = Zero::zero();
+		let round: RoundId = One::one();
+		// start the round without answering it
+		assert_is_ok(ChainlinkFeed::<T>::submit(RawOrigin::Signed(oracles[0].0.clone()).into(), feed, round, 42u8.into()));
+		let answer: T::Value = 5u8.into();
+	}: _(RawOrigin::Signed(pallet_admin.clone()), feed, round, answer)
+	verify {
+		let f = <Feed<T>>::read_only_from(feed).unwrap();
+		assert_eq!(f.latest_data().answer, answer);
+	}
+
+	pallet_admin_override_feed_owner {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(RawOrigin::Signed(pallet_admin.clone()).into(), caller.clone()));
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller.clone()).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			vec![1; T::StringLimit::get() as usize],
+			Zero::zero(),
+			vec![(oracle, admin)],
+			None,
+			None,
+			0,
+			vec![],
+		));
+		let feed = Zero::zero();
+		let new_owner: T::AccountId = account("new_owner", 0, SEED);
+	}: _(RawOrigin::Signed(pallet_admin.clone()), feed, new_owner.clone())
+	verify {
+		assert_eq!(ChainlinkFeed::<T>::feed_config(feed).expect("feed should be there").owner, new_owner);
+	}
+
 	transfer_pallet_admin {
 		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
 		whitelist_acc::<T>(&pallet_admin);
@@ -623,6 +1237,27 @@ benchmarks! {
 		assert_eq!(PendingPalletAdmin::<T>::get(), None);
 	}
 
+	cancel_pallet_admin_transfer {
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		whitelist_acc::<T>(&pallet_admin);
+		let new_admin: T::AccountId = account("new_pallet_admin", 0, SEED);
+		assert_is_ok(ChainlinkFeed::<T>::transfer_pallet_admin(
+			RawOrigin::Signed(pallet_admin.clone()).into(),
+			new_admin
+		));
+	}: _(RawOrigin::Signed(pallet_admin))
+	verify {
+		assert_eq!(PendingPalletAdmin::<T>::get(), None);
+	}
+
+	force_set_pallet_admin {
+		let new_admin: T::AccountId = account("new_pallet_admin", 0, SEED);
+		let origin = T::GovernanceOrigin::successful_origin();
+	}: _<T::Origin>(origin, new_admin.clone())
+	verify {
+		assert_eq!(ChainlinkFeed::<T>::pallet_admin(), new_admin);
+	}
+
 	set_feed_creator {
 		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
 		whitelist_acc::<T>(&pallet_admin);
@@ -787,4 +1422,210 @@ mod tests {
 			assert_ok!(test_benchmark_remove_feed_creator::<Test>());
 		});
 	}
+
+	#[test]
+	fn set_deviation_alert() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_deviation_alert::<Test>());
+		});
+	}
+
+	#[test]
+	fn update_feed_config() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_update_feed_config::<Test>());
+		});
+	}
+
+	#[test]
+	fn prune_all_expired() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_prune_all_expired::<Test>());
+		});
+	}
+
+	#[test]
+	fn prune_single_round() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_prune_single_round::<Test>());
+		});
+	}
+
+	#[test]
+	fn set_round_payment() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_round_payment::<Test>());
+		});
+	}
+
+	#[test]
+	fn withdraw_payment_all() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_withdraw_payment_all::<Test>());
+		});
+	}
+
+	#[test]
+	fn transfer_admin_batch() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_transfer_admin_batch::<Test>());
+		});
+	}
+
+	#[test]
+	fn force_answer() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_force_answer::<Test>());
+		});
+	}
+
+	#[test]
+	fn force_set_pallet_admin() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_force_set_pallet_admin::<Test>());
+		});
+	}
+
+	#[test]
+	fn close_round() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_close_round::<Test>());
+		});
+	}
+
+	#[test]
+	fn cancel_pallet_admin_transfer() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_cancel_pallet_admin_transfer::<Test>());
+		});
+	}
+
+	#[test]
+	fn set_min_oracle_count() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_min_oracle_count::<Test>());
+		});
+	}
+	#[test]
+	fn set_tags() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_tags::<Test>());
+		});
+	}
+
+	#[test]
+	fn set_payment_amount() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_payment_amount::<Test>());
+		});
+	}
+
+	#[test]
+	fn set_timeout() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_set_timeout::<Test>());
+		});
+	}
+
+	#[test]
+	fn pallet_admin_override_feed_owner() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_pallet_admin_override_feed_owner::<Test>());
+		});
+	}
+
+	#[test]
+	fn deprecate_feed() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_deprecate_feed::<Test>());
+		});
+	}
+
+	#[test]
+	fn undeprecate_feed() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_undeprecate_feed::<Test>());
+		});
+	}
+
+	#[test]
+	fn close_timed_out_round() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_close_timed_out_round::<Test>());
+		});
+	}
+}
+
+/// One-off comparison of the key hasher of `Feeds` and `Rounds` with `Blake2_128Concat`.
+///
+/// Not a regular benchmark as it does not measure an extrinsic and has no `WeightInfo`
+/// entry. Run it on the target hardware with
+/// `cargo test --release --features runtime-benchmarks feed_key_hashers -- --ignored --nocapture`.
+#[cfg(test)]
+mod hashers {
+	use super::*;
+	use crate::mock::{new_test_ext, Test};
+	use codec::Encode;
+	use frame_support::{storage::unhashed, Blake2_128Concat, StorageHasher, Twox64Concat};
+	use std::time::{Duration, Instant};
+
+	/// Create a feed with the maximum description length and return its config.
+	fn bench_feed_config<T: Config>() -> FeedConfigOf<T> {
+		let caller: T::AccountId = whitelisted_caller();
+		let pallet_admin: T::AccountId = ChainlinkFeed::<T>::pallet_admin();
+		assert_is_ok(ChainlinkFeed::<T>::set_feed_creator(
+			RawOrigin::Signed(pallet_admin).into(),
+			caller.clone(),
+		));
+		let admin: T::AccountId = account("oracle_admin", 0, SEED);
+		let oracle: T::AccountId = account("oracle", 0, SEED);
+		let description = vec![1; T::StringLimit::get() as usize];
+		assert_is_ok(ChainlinkFeed::<T>::create_feed(
+			RawOrigin::Signed(caller).into(),
+			600u32.into(),
+			Zero::zero(),
+			(1u8.into(), 100u8.into()),
+			1u8.into(),
+			5u8.into(),
+			description,
+			Zero::zero(),
+			vec![(oracle, admin)],
+			None,
+			None,
+			0,
+			vec![],
+		));
+		ChainlinkFeed::<T>::feed_config(T::FeedId::zero()).expect("feed should be there")
+	}
+
+	/// A storage key for the feed `id` hashed with `H`, outside of the pallet storage.
+	fn bench_feed_key<H: StorageHasher>(id: u32) -> Vec<u8> {
+		let mut key = b"chainlink_feed_hasher_bench".to_vec();
+		key.extend_from_slice(H::hash(&id.encode()).as_ref());
+		key
+	}
+
+	/// Write and read `n` feed configs under keys hashed with `H`.
+	fn measure<H: StorageHasher>(n: u32) -> Duration {
+		let config = bench_feed_config::<Test>();
+		let start = Instant::now();
+		for id in 0..n {
+			let key = bench_feed_key::<H>(id);
+			unhashed::put(&key, &config);
+			let _: Option<FeedConfigOf<Test>> = unhashed::get(&key);
+		}
+		start.elapsed()
+	}
+
+	#[test]
+	#[ignore]
+	fn feed_key_hashers() {
+		let n = 10_000;
+		let twox = new_test_ext().execute_with(|| measure::<Twox64Concat>(n));
+		let blake2 = new_test_ext().execute_with(|| measure::<Blake2_128Concat>(n));
+		println!(
+			"{} feed configs: twox_64_concat {:?}, blake2_128_concat {:?}",
+			n, twox, blake2
+		);
+	}
 }
@@ -0,0 +1,247 @@
+//! Builder for feed configs
+//!
+//! Allows other pallets to assemble a [`FeedConfig`] without the long positional
+//! argument list of `create_feed`.
+//!
+//! **Note:** The builder does NOT write to storage. Callers have to pass the result
+//! to `create_feed`.
+use frame_support::{traits::Get, RuntimeDebug};
+use sp_runtime::{
+	traits::{Bounded, One, Zero},
+	DispatchError,
+};
+use sp_std::{
+	convert::{TryFrom, TryInto},
+	prelude::*,
+};
+
+use crate::{
+	validate_feed_config, BalanceOf, Config, Error, FeedConfig, FeedConfigOf, RoundId, TagOf,
+};
+
+/// Possible errors when building a feed config.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum BuilderError {
+	/// The description string is too long.
+	DescriptionTooLong,
+	/// The pruning window has to be greater than zero.
+	CannotPruneRoundZero,
+	/// Tried to add too many oracles.
+	OraclesLimitExceeded,
+	/// The specified min/max pair was invalid.
+	WrongBounds,
+	/// Feeds need at least one submission per round.
+	ZeroMinSubmissions,
+	/// The maximum number of submissions cannot exceed the amount of oracles.
+	MaxExceededTotal,
+	/// The round initiation delay has to be below the number of oracles.
+	DelayNotBelowCount,
+	/// There are fewer oracles than the minimum oracle count.
+	BelowMinimumOracleCount,
+	/// A tag is longer than `TagLimit`.
+	TagTooLong,
+	/// More tags than `MaxTags` were provided.
+	TooManyTags,
+	/// The treasury share of the payment is above 100 percent.
+	TreasuryPercentTooHigh,
+}
+
+impl BuilderError {
+	/// Map an error returned by [`validate_feed_config`] to the builder error.
+	fn from_validation_error<T: Config>(error: DispatchError) -> Self {
+		use BuilderError::*;
+		// these are all errors `validate_feed_config` can return
+		[
+			CannotPruneRoundZero,
+			OraclesLimitExceeded,
+			WrongBounds,
+			ZeroMinSubmissions,
+			MaxExceededTotal,
+			DelayNotBelowCount,
+			BelowMinimumOracleCount,
+			TreasuryPercentTooHigh,
+		]
+		.iter()
+		.find(|e| DispatchError::from(Error::<T>::from((*e).clone())) == error)
+		.cloned()
+		.unwrap_or(WrongBounds)
+	}
+}
+
+impl<T: Config> From<BuilderError> for Error<T> {
+	fn from(e: BuilderError) -> Self {
+		match e {
+			BuilderError::DescriptionTooLong => Error::<T>::DescriptionTooLong,
+			BuilderError::CannotPruneRoundZero => Error::<T>::CannotPruneRoundZero,
+			BuilderError::OraclesLimitExceeded => Error::<T>::OraclesLimitExceeded,
+			BuilderError::WrongBounds => Error::<T>::WrongBounds,
+			BuilderError::ZeroMinSubmissions => Error::<T>::ZeroMinSubmissions,
+			BuilderError::MaxExceededTotal => Error::<T>::MaxExceededTotal,
+			BuilderError::DelayNotBelowCount => Error::<T>::DelayNotBelowCount,
+			BuilderError::BelowMinimumOracleCount => Error::<T>::BelowMinimumOracleCount,
+			BuilderError::TagTooLong => Error::<T>::TagTooLong,
+			BuilderError::TooManyTags => Error::<T>::TooManyTags,
+			BuilderError::TreasuryPercentTooHigh => Error::<T>::TreasuryPercentTooHigh,
+		}
+	}
+}
+
+/// Builder for a [`FeedConfig`] and the oracles of the feed.
+pub struct FeedConfigBuilder<T: Config> {
+	owner: T::AccountId,
+	payment: BalanceOf<T>,
+	timeout: T::BlockNumber,
+	value_bounds: (T::Value, T::Value),
+	count_bounds: Option<(u32, u32)>,
+	decimals: u8,
+	description: Vec<u8>,
+	restart_delay: RoundId,
+	oracles: Vec<(T::AccountId, T::AccountId)>,
+	pruning_window: Option<RoundId>,
+	max_debt: Option<BalanceOf<T>>,
+	min_oracle_count: u32,
+	tags: Vec<Vec<u8>>,
+}
+
+impl<T: Config> FeedConfigBuilder<T> {
+	/// Start building a feed config owned by `owner`.
+	///
+	/// Submission values default to the range up to `MaxSubmissionValue` and the
+	/// submission count bounds default to `(1, number of oracles)`.
+	pub fn new(owner: T::AccountId) -> Self {
+		Self {
+			owner,
+			payment: Zero::zero(),
+			timeout: Zero::zero(),
+			value_bounds: (T::Value::min_value(), T::MaxSubmissionValue::get()),
+			count_bounds: None,
+			decimals: Zero::zero(),
+			description: Vec::new(),
+			restart_delay: Zero::zero(),
+			oracles: Vec::new(),
+			pruning_window: None,
+			max_debt: None,
+			min_oracle_count: Zero::zero(),
+			tags: Vec::new(),
+		}
+	}
+
+	pub fn payment(mut self, payment: BalanceOf<T>) -> Self {
+		self.payment = payment;
+		self
+	}
+
+	pub fn timeout(mut self, timeout: T::BlockNumber) -> Self {
+		self.timeout = timeout;
+		self
+	}
+
+	pub fn value_bounds(mut self, min: T::Value, max: T::Value) -> Self {
+		self.value_bounds = (min, max);
+		self
+	}
+
+	pub fn count_bounds(mut self, min: u32, max: u32) -> Self {
+		self.count_bounds = Some((min, max));
+		self
+	}
+
+	pub fn decimals(mut self, decimals: u8) -> Self {
+		self.decimals = decimals;
+		self
+	}
+
+	pub fn description(mut self, description: Vec<u8>) -> Self {
+		self.description = description;
+		self
+	}
+
+	pub fn restart_delay(mut self, restart_delay: RoundId) -> Self {
+		self.restart_delay = restart_delay;
+		self
+	}
+
+	/// The `(oracle, admin)` pairs to add to the feed.
+	pub fn oracles(mut self, oracles: Vec<(T::AccountId, T::AccountId)>) -> Self {
+		self.oracles = oracles;
+		self
+	}
+
+	pub fn pruning_window(mut self, pruning_window: RoundId) -> Self {
+		self.pruning_window = Some(pruning_window);
+		self
+	}
+
+	pub fn max_debt(mut self, max_debt: BalanceOf<T>) -> Self {
+		self.max_debt = Some(max_debt);
+		self
+	}
+
+	pub fn min_oracle_count(mut self, min_oracle_count: u32) -> Self {
+		self.min_oracle_count = min_oracle_count;
+		self
+	}
+
+	/// The tags of the feed. Only `create_feed` adds them to the `FeedsByTag` index.
+	pub fn tags(mut self, tags: Vec<Vec<u8>>) -> Self {
+		self.tags = tags;
+		self
+	}
+
+	/// Validate the parameters with the same checks as `create_feed` and return the
+	/// config together with the oracles.
+	///
+	/// The returned config has an `oracle_count` of zero as the oracles still need to
+	/// be added to the feed. Its `id` is zero until the feed is created.
+	pub fn build(
+		self,
+	) -> Result<(FeedConfigOf<T>, Vec<(T::AccountId, T::AccountId)>), BuilderError> {
+		let oracle_count = self.oracles.len() as u32;
+		let (min, max) = self.count_bounds.unwrap_or((One::one(), oracle_count));
+		let description = self
+			.description
+			.try_into()
+			.map_err(|_| BuilderError::DescriptionTooLong)?;
+		let tags = self
+			.tags
+			.into_iter()
+			.map(|tag| TagOf::<T>::try_from(tag).map_err(|_| BuilderError::TagTooLong))
+			.collect::<Result<Vec<_>, _>>()?
+			.try_into()
+			.map_err(|_| BuilderError::TooManyTags)?;
+
+		let config = FeedConfig {
+			// assigned when the feed is created
+			id: Zero::zero(),
+			owner: self.owner,
+			pending_owner: None,
+			submission_value_bounds: self.value_bounds,
+			submission_count_bounds: (min, max),
+			payment: self.payment,
+			timeout: self.timeout,
+			decimals: self.decimals,
+			description,
+			restart_delay: self.restart_delay,
+			reporting_round: Zero::zero(),
+			latest_round: Zero::zero(),
+			first_valid_round: None,
+			oracle_count: Zero::zero(),
+			pruning_window: self.pruning_window.unwrap_or(RoundId::MAX),
+			next_round_to_prune: One::one(),
+			debt: Zero::zero(),
+			max_debt: self.max_debt,
+			deviation_alert_percent: Zero::zero(),
+			treasury_percent: Zero::zero(),
+			max_rounds_open: Zero::zero(),
+			created_at: frame_system::Pallet::<T>::block_number(),
+			min_oracle_count: self.min_oracle_count,
+			tags,
+			submission_deadline_offset: None,
+			deprecated: false,
+			version: crate::CURRENT_VERSION,
+		};
+		validate_feed_config::<T>(&config, oracle_count)
+			.map_err(BuilderError::from_validation_error::<T>)?;
+		Ok((config, self.oracles))
+	}
+}
@@ -23,20 +23,27 @@
 // --output
 // ../pallet-chainlink-feed/src/default_weights.rs
 
+// NOTE: The functions marked as placeholders were written by hand instead of being
+// generated by the benchmark CLI. Rerun the command above to replace them with
+// measured weights.
+
 #![allow(unused_parens)]
 #![allow(unused_imports)]
 
 use frame_support::weights::{constants::RocksDbWeight as DbWeight, Weight};
 
 impl crate::WeightInfo for () {
-	fn create_feed(o: u32) -> Weight {
+	// Placeholder: written by hand, not benchmarked.
+	fn create_feed(o: u32, t: u32) -> Weight {
 		(554_583_000 as Weight)
 			// Standard Error: 184_000
 			.saturating_add((291_193_000 as Weight).saturating_mul(o as Weight))
-			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add((4_312_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
 			.saturating_add(DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
 			.saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(t as Weight)))
 	}
 	fn transfer_ownership() -> Weight {
 		(304_000_000 as Weight)
@@ -55,17 +62,57 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(1 as Weight))
 			.saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn prune_single_round() -> Weight {
+		(318_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_deviation_alert() -> Weight {
+		(301_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_min_oracle_count() -> Weight {
+		(301_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_tags(t: u32) -> Weight {
+		(301_254_000 as Weight)
+			.saturating_add((9_843_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+			.saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn deprecate_feed() -> Weight {
+		(301_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn undeprecate_feed() -> Weight {
+		(301_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
 	fn submit_opening_round_answers() -> Weight {
 		(1_522_000_000 as Weight)
-			.saturating_add(DbWeight::get().reads(6 as Weight))
-			.saturating_add(DbWeight::get().writes(6 as Weight))
+			.saturating_add(DbWeight::get().reads(9 as Weight))
+			.saturating_add(DbWeight::get().writes(9 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
 	fn submit_closing_answer(o: u32) -> Weight {
 		(1_187_235_000 as Weight)
 			// Standard Error: 148_000
 			.saturating_add((1_754_000 as Weight).saturating_mul(o as Weight))
-			.saturating_add(DbWeight::get().reads(7 as Weight))
-			.saturating_add(DbWeight::get().writes(6 as Weight))
+			.saturating_add(DbWeight::get().reads(10 as Weight))
+			.saturating_add(DbWeight::get().writes(9 as Weight))
 	}
 	fn change_oracles(d: u32, n: u32) -> Weight {
 		(0 as Weight)
@@ -85,6 +132,42 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(1 as Weight))
 			.saturating_add(DbWeight::get().writes(1 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_payment_amount() -> Weight {
+		(311_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_timeout() -> Weight {
+		(309_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn update_feed_config() -> Weight {
+		(420_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_round_payment() -> Weight {
+		(372_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn close_round() -> Weight {
+		(498_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn close_timed_out_round() -> Weight {
+		(402_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
 	fn set_requester() -> Weight {
 		(378_000_000 as Weight)
 			.saturating_add(DbWeight::get().reads(2 as Weight))
@@ -105,11 +188,27 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(3 as Weight))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn withdraw_payment_all(o: u32) -> Weight {
+		(502_000_000 as Weight)
+			.saturating_add((398_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+			.saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
 	fn transfer_admin() -> Weight {
 		(314_000_000 as Weight)
 			.saturating_add(DbWeight::get().reads(1 as Weight))
 			.saturating_add(DbWeight::get().writes(1 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn transfer_admin_batch(t: u32) -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add((296_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(DbWeight::get().writes((1 as Weight).saturating_mul(t as Weight)))
+	}
 	fn accept_admin() -> Weight {
 		(315_000_000 as Weight)
 			.saturating_add(DbWeight::get().reads(1 as Weight))
@@ -125,6 +224,28 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(2 as Weight))
 			.saturating_add(DbWeight::get().writes(2 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn prune_all_expired(f: u32, r: u32) -> Weight {
+		(102_000_000 as Weight)
+			.saturating_add((41_873_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add((67_512_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(f as Weight)))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+			.saturating_add(DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn force_answer() -> Weight {
+		(412_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn pallet_admin_override_feed_owner() -> Weight {
+		(250_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
 	fn transfer_pallet_admin() -> Weight {
 		(262_000_000 as Weight)
 			.saturating_add(DbWeight::get().reads(1 as Weight))
@@ -135,6 +256,16 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(1 as Weight))
 			.saturating_add(DbWeight::get().writes(2 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn cancel_pallet_admin_transfer() -> Weight {
+		(221_000_000 as Weight)
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn force_set_pallet_admin() -> Weight {
+		(231_000_000 as Weight).saturating_add(DbWeight::get().writes(2 as Weight))
+	}
 	fn set_feed_creator() -> Weight {
 		(278_000_000 as Weight)
 			.saturating_add(DbWeight::get().reads(1 as Weight))
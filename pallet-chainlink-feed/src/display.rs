@@ -0,0 +1,97 @@
+//! Human readable representations of the pallet types
+//!
+//! Every field is printed on its own line as `label: value`. Accounts are printed
+//! as the hex encoding of their SCALE encoding (like in [`crate::rpc`]).
+use frame_support::{traits::Get, Parameter};
+use std::fmt::{self, Display, Formatter};
+
+use crate::{rpc::account_to_hex, FeedConfig, RoundData};
+
+/// Display helper printing `none` for missing values.
+struct OptionDisplay<'a, T>(&'a Option<T>);
+
+impl<'a, T: Display> Display for OptionDisplay<'a, T> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self.0 {
+			Some(v) => v.fmt(f),
+			None => f.write_str("none"),
+		}
+	}
+}
+
+impl<FeedId, AccountId, Balance, BlockNumber, Value, StringLimit, TagLimit, MaxTags> Display
+	for FeedConfig<FeedId, AccountId, Balance, BlockNumber, Value, StringLimit, TagLimit, MaxTags>
+where
+	FeedId: Parameter + Display,
+	AccountId: Parameter,
+	Balance: Parameter + Display,
+	BlockNumber: Parameter + Display,
+	Value: Parameter + Display,
+	StringLimit: Get<u32>,
+	TagLimit: Get<u32>,
+	MaxTags: Get<u32>,
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		let (min_value, max_value) = &self.submission_value_bounds;
+		let (min_count, max_count) = self.submission_count_bounds;
+		let pending_owner = self.pending_owner.as_ref().map(account_to_hex);
+		writeln!(f, "id: {}", self.id)?;
+		writeln!(f, "owner: {}", account_to_hex(&self.owner))?;
+		writeln!(f, "pending_owner: {}", OptionDisplay(&pending_owner))?;
+		writeln!(f, "submission_value_bounds: ({}, {})", min_value, max_value)?;
+		writeln!(f, "submission_count_bounds: ({}, {})", min_count, max_count)?;
+		writeln!(f, "payment: {}", self.payment)?;
+		writeln!(f, "timeout: {}", self.timeout)?;
+		writeln!(f, "decimals: {}", self.decimals)?;
+		writeln!(
+			f,
+			"description: {}",
+			String::from_utf8_lossy(&self.description)
+		)?;
+		writeln!(f, "restart_delay: {}", self.restart_delay)?;
+		writeln!(f, "reporting_round: {}", self.reporting_round)?;
+		writeln!(f, "latest_round: {}", self.latest_round)?;
+		writeln!(
+			f,
+			"first_valid_round: {}",
+			OptionDisplay(&self.first_valid_round)
+		)?;
+		writeln!(f, "oracle_count: {}", self.oracle_count)?;
+		writeln!(f, "pruning_window: {}", self.pruning_window)?;
+		writeln!(f, "next_round_to_prune: {}", self.next_round_to_prune)?;
+		writeln!(f, "debt: {}", self.debt)?;
+		writeln!(f, "max_debt: {}", OptionDisplay(&self.max_debt))?;
+		writeln!(
+			f,
+			"deviation_alert_percent: {}",
+			self.deviation_alert_percent
+		)?;
+		writeln!(f, "treasury_percent: {}", self.treasury_percent)?;
+		writeln!(f, "max_rounds_open: {}", self.max_rounds_open)?;
+		writeln!(f, "created_at: {}", self.created_at)?;
+		writeln!(f, "min_oracle_count: {}", self.min_oracle_count)?;
+		writeln!(
+			f,
+			"submission_deadline_offset: {}",
+			OptionDisplay(&self.submission_deadline_offset)
+		)?;
+		writeln!(f, "deprecated: {}", self.deprecated)?;
+		writeln!(f, "version: {}", self.version)?;
+		let tags: Vec<_> = self
+			.tags
+			.iter()
+			.map(|tag| String::from_utf8_lossy(tag))
+			.collect();
+		write!(f, "tags: [{}]", tags.join(", "))
+	}
+}
+
+impl<BlockNumber: Display, Value: Display> Display for RoundData<BlockNumber, Value> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		writeln!(f, "round_id: {}", self.round_id)?;
+		writeln!(f, "started_at: {}", self.started_at)?;
+		writeln!(f, "answer: {}", self.answer)?;
+		writeln!(f, "updated_at: {}", self.updated_at)?;
+		write!(f, "answered_in_round: {}", self.answered_in_round)
+	}
+}
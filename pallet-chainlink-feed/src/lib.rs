@@ -6,40 +6,67 @@ pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod builder;
 #[cfg(test)]
 pub(crate) mod mock;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 #[cfg(test)]
 pub mod tests;
 pub mod traits;
 
 pub mod default_weights;
+#[cfg(feature = "std")]
+mod display;
+pub mod migrations;
+#[cfg(feature = "std")]
+pub mod rpc;
 mod utils;
+#[cfg(feature = "std")]
+pub mod verification;
+
+/// Only exposed for the fuzz targets in `fuzz/`.
+#[cfg(feature = "fuzzing")]
+pub use utils::median;
+
+/// The schema version of `FeedConfig` set on newly created feeds.
+///
+/// Stored configs created before the field was introduced count as version `0`.
+/// Bump this whenever `FeedConfig` changes and migrate the stored configs.
+pub const CURRENT_VERSION: u16 = 1;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use codec::{Decode, Encode};
 	use frame_support::dispatch::DispatchResultWithPostInfo;
-	use frame_support::traits::{Currency, ExistenceRequirement, Get, ReservableCurrency};
+	use frame_support::traits::{
+		Currency, ExistenceRequirement, Get, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+		UnixTime,
+	};
 	use frame_support::{
 		dispatch::{DispatchError, DispatchResult, HasCompact},
 		ensure,
 		pallet_prelude::*,
 		require_transactional,
+		storage::{with_transaction, TransactionOutcome},
+		transactional,
 		weights::Weight,
-		PalletId, Parameter, RuntimeDebug,
+		BoundedVec, CloneNoBound, EqNoBound, PalletId, Parameter, PartialEqNoBound, RuntimeDebug,
+		RuntimeDebugNoBound,
 	};
 	use frame_system::ensure_signed;
 	use frame_system::pallet_prelude::*;
-	use sp_arithmetic::traits::BaseArithmetic;
+	use sp_arithmetic::{traits::BaseArithmetic, PerThing, Percent};
 	use sp_runtime::traits::{
-		AccountIdConversion, CheckedAdd, CheckedSub, Member, One, Saturating, Zero,
+		AccountIdConversion, CheckedAdd, CheckedSub, Hash as HashT, IdentifyAccount, Member, One,
+		Saturating, UniqueSaturatedInto, Verify, Zero,
 	};
 	use sp_std::convert::{TryFrom, TryInto};
 	use sp_std::prelude::*;
 
 	use crate::{
-		traits::OnAnswerHandler,
-		utils::{median, with_transaction_result},
+		traits::{AnswerValidator, ChainlinkAggregatorV3, OnAnswerHandler},
+		utils::{compute_moving_average, exceeds_deviation, median, std_dev},
 	};
 
 	pub type BalanceOf<T> =
@@ -48,13 +75,19 @@ pub mod pallet {
 	pub type RoundId = u32;
 
 	/// The configuration for an oracle feed.
-	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
+	#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound)]
 	pub struct FeedConfig<
+		FeedId: Parameter,
 		AccountId: Parameter,
 		Balance: Parameter,
 		BlockNumber: Parameter,
 		Value: Parameter,
+		StringLimit: Get<u32>,
+		TagLimit: Get<u32>,
+		MaxTags: Get<u32>,
 	> {
+		/// Id of this feed (the key under which the config is stored)
+		pub id: FeedId,
 		/// Owner of this feed
 		pub owner: AccountId,
 		/// The pending owner of this feed
@@ -70,7 +103,7 @@ pub mod pallet {
 		/// Represents the number of decimals with which the feed is configured
 		pub decimals: u8,
 		/// The description of this feed
-		pub description: Vec<u8>,
+		pub description: BoundedVec<u8, StringLimit>,
 		/// The round initiation delay
 		pub restart_delay: RoundId,
 		/// The round oracles are currently reporting data for.
@@ -92,13 +125,79 @@ pub mod pallet {
 		///
 		/// If this is a `None` value, the feed is not allowed to accumulate any debt
 		pub max_debt: Option<Balance>,
+		/// Deviation (in percent) between consecutive answers above which
+		/// an `AnswerDeviationHigh` event is emitted. `0` disables the alert.
+		pub deviation_alert_percent: u8,
+		/// Share (in percent, rounded down) of every oracle payment that is transferred
+		/// to the treasury account instead of the oracle.
+		///
+		/// The share is transferred on submission, so submissions fail if the pallet
+		/// fund cannot cover it (unlike the oracle share, it does not accrue debt).
+		pub treasury_percent: u8,
+		/// The maximum number of rounds with stored details (i.e. rounds that still
+		/// accept submissions) before new rounds are rejected. `0` disables the limit.
+		///
+		/// **Note:** Answered rounds keep their details until the next round is answered.
+		pub max_rounds_open: u32,
+		/// The block in which the feed was created
+		pub created_at: BlockNumber,
+		/// The number of oracles the feed cannot drop below. `0` disables the limit.
+		pub min_oracle_count: u32,
+		/// Tags for categorizing the feed (e.g. `crypto`)
+		pub tags: BoundedVec<BoundedVec<u8, TagLimit>, MaxTags>,
+		/// Number of blocks after the start of a round after which its submissions
+		/// are rejected. `None` disables the deadline.
+		pub submission_deadline_offset: Option<BlockNumber>,
+		/// Whether the feed reached its end of life and no longer accepts submissions
+		pub deprecated: bool,
+		/// The schema version of the config (see `CURRENT_VERSION`)
+		pub version: u16,
 	}
 
 	pub type FeedConfigOf<T> = FeedConfig<
+		<T as Config>::FeedId,
 		<T as frame_system::Config>::AccountId,
 		BalanceOf<T>,
 		<T as frame_system::Config>::BlockNumber,
 		<T as Config>::Value,
+		<T as Config>::StringLimit,
+		<T as Config>::TagLimit,
+		<T as Config>::MaxTags,
+	>;
+
+	/// A single feed tag.
+	pub type TagOf<T> = BoundedVec<u8, <T as Config>::TagLimit>;
+
+	/// Changes to apply to a feed config via `update_feed_config`.
+	/// Fields set to `None` are left unchanged.
+	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
+	pub struct FeedConfigUpdates<Balance, BlockNumber, Value> {
+		/// Payment of oracle rounds
+		pub payment: Option<Balance>,
+		/// Timeout of rounds
+		pub timeout: Option<BlockNumber>,
+		/// Value bounds of oracle submissions
+		pub submission_value_bounds: Option<(Value, Value)>,
+		/// Count bounds of oracle submissions
+		pub submission_count_bounds: Option<(u32, u32)>,
+		/// The round initiation delay
+		pub restart_delay: Option<RoundId>,
+		/// The description of the feed
+		pub description: Option<Vec<u8>>,
+		/// Deviation (in percent) above which `AnswerDeviationHigh` is emitted
+		pub deviation_alert_percent: Option<u8>,
+		/// Share (in percent) of oracle payments going to the treasury
+		pub treasury_percent: Option<u8>,
+		/// The maximum number of open rounds (`0` for no limit)
+		pub max_rounds_open: Option<u32>,
+		/// The submission deadline offset of new rounds (`Some(None)` removes it)
+		pub submission_deadline_offset: Option<Option<BlockNumber>>,
+	}
+
+	pub type FeedConfigUpdatesOf<T> = FeedConfigUpdates<
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::Value,
 	>;
 
 	/// Round data relevant to consumers.
@@ -129,16 +228,66 @@ pub mod pallet {
 	}
 
 	/// Round data relevant to oracles.
-	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
-	pub struct RoundDetails<Balance, BlockNumber, Value> {
-		pub submissions: Vec<Value>,
+	///
+	/// The submissions are bounded by `Limit` (the maximum number of oracles per feed)
+	/// because there can be at most one submission per oracle.
+	#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound)]
+	pub struct RoundDetails<
+		AccountId: Parameter,
+		Balance: Parameter,
+		BlockNumber: Parameter,
+		Value: Parameter,
+		Limit: Get<u32>,
+	> {
+		pub submissions: BoundedVec<Value, Limit>,
 		pub submission_count_bounds: (u32, u32),
 		pub payment: Balance,
 		pub timeout: BlockNumber,
+		/// The oracle or requester that started the round
+		pub started_by: AccountId,
+		/// The last block in which submissions to the round are accepted
+		pub deadline: Option<BlockNumber>,
+	}
+
+	pub type RoundDetailsOf<T> = RoundDetails<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::Value,
+		<T as Config>::OracleCountLimit,
+	>;
+
+	/// The state of a feed at a single block, as returned by `Pallet::snapshot`.
+	#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound)]
+	pub struct FeedSnapshot<T: Config> {
+		pub config: FeedConfigOf<T>,
+		/// The latest answered round.
+		pub latest_round: RoundOf<T>,
+		/// The details of the round oracles are currently reporting for.
+		/// `None` if no details are stored for that round.
+		pub latest_details: Option<RoundDetailsOf<T>>,
+		/// The number of oracles that are not disabled.
+		pub active_oracle_count: u32,
+	}
+
+	/// Compact summary of a feed for listing many feeds at once.
+	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
+	pub struct FeedSummary<FeedId, Hash, Value, BlockNumber> {
+		pub id: FeedId,
+		/// The hash of the description as used by the description index
+		pub description_hash: Hash,
+		/// `None` if the feed has not been answered, yet.
+		pub latest_answer: Option<Value>,
+		pub updated_at: Option<BlockNumber>,
+		pub decimals: u8,
 	}
 
-	pub type RoundDetailsOf<T> =
-		RoundDetails<BalanceOf<T>, <T as frame_system::Config>::BlockNumber, <T as Config>::Value>;
+	pub type FeedSummaryOf<T> = FeedSummary<
+		<T as Config>::FeedId,
+		<T as frame_system::Config>::Hash,
+		<T as Config>::Value,
+		<T as frame_system::Config>::BlockNumber,
+	>;
 
 	/// Meta data tracking withdrawable rewards and admin for an oracle.
 	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
@@ -146,33 +295,49 @@ pub mod pallet {
 		pub withdrawable: Balance,
 		pub admin: AccountId,
 		pub pending_admin: Option<AccountId>,
+		/// The sum of all payments the oracle received
+		pub total_earned: Balance,
+		/// The sum of all payments withdrawn for the oracle
+		pub total_withdrawn: Balance,
 	}
 
 	pub type OracleMetaOf<T> = OracleMeta<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
 
 	/// Meta data tracking the oracle status for a feed.
 	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
-	pub struct OracleStatus<Value> {
+	pub struct OracleStatus<BlockNumber, Value, Balance> {
 		pub starting_round: RoundId,
 		pub ending_round: Option<RoundId>,
 		pub last_reported_round: Option<RoundId>,
 		pub last_started_round: Option<RoundId>,
 		pub latest_submission: Option<Value>,
+		/// The block of the latest submission
+		pub last_reported_block: Option<BlockNumber>,
+		/// The number of submissions of the oracle to the feed
+		pub submission_count: u32,
+		/// The block at which the oracle was (last) enabled
+		pub enabled_since: BlockNumber,
+		/// The sum of the payments the oracle received for submissions to the feed
+		pub cumulative_earnings: Balance,
 	}
 
 	/// Minimum and Maximum number of submissions allowed per round.
 	pub type SubmissionBounds = (u32, u32);
 
-	pub type OracleStatusOf<T> = OracleStatus<<T as Config>::Value>;
+	pub type OracleStatusOf<T> =
+		OracleStatus<<T as frame_system::Config>::BlockNumber, <T as Config>::Value, BalanceOf<T>>;
 
-	impl<Value> OracleStatus<Value>
+	impl<BlockNumber, Value, Balance> OracleStatus<BlockNumber, Value, Balance>
 	where
+		BlockNumber: Default,
 		Value: Default,
+		Balance: Default,
 	{
-		/// Create a new oracle status with the given `starting_round`.
-		fn new(starting_round: RoundId) -> Self {
+		/// Create a new oracle status with the given `starting_round` and `enabled_since`.
+		fn new(starting_round: RoundId, enabled_since: BlockNumber) -> Self {
 			Self {
 				starting_round,
+				enabled_since,
 				..Default::default()
 			}
 		}
@@ -188,6 +353,8 @@ pub mod pallet {
 	/// Round data as served by the `FeedInterface`.
 	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
 	pub struct RoundData<BlockNumber, Value> {
+		/// The id of the round the data belongs to
+		pub round_id: RoundId,
 		pub started_at: BlockNumber,
 		pub answer: Value,
 		pub updated_at: BlockNumber,
@@ -198,20 +365,59 @@ pub mod pallet {
 		RoundData<<T as frame_system::Config>::BlockNumber, <T as Config>::Value>;
 
 	/// Possible error when converting from `Round` to `RoundData`.
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug)]
 	pub enum RoundConversionError {
 		MissingField,
 	}
 
-	// Implements a conversion from `Round` to `RoundData` so answered rounds can be converted easily.
-	impl<B, V> TryFrom<Round<B, V>> for RoundData<B, V> {
+	impl From<RoundConversionError> for DispatchError {
+		fn from(e: RoundConversionError) -> Self {
+			match e {
+				RoundConversionError::MissingField => {
+					DispatchError::Other("RoundConversionError::MissingField")
+				}
+			}
+		}
+	}
+
+	/// Reasons why a feed cannot provide its latest data.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug)]
+	pub enum FeedError {
+		/// The feed has not been answered, yet.
+		NoDataYet,
+		/// The latest round was pruned from storage.
+		PrunedRound,
+	}
+
+	impl From<RoundConversionError> for FeedError {
+		fn from(e: RoundConversionError) -> Self {
+			match e {
+				// only answered rounds have all fields set
+				RoundConversionError::MissingField => FeedError::NoDataYet,
+			}
+		}
+	}
+
+	impl From<FeedError> for DispatchError {
+		fn from(e: FeedError) -> Self {
+			match e {
+				FeedError::NoDataYet => DispatchError::Other("FeedError::NoDataYet"),
+				FeedError::PrunedRound => DispatchError::Other("FeedError::PrunedRound"),
+			}
+		}
+	}
+
+	// Implements a conversion from `Round` (together with its id) to `RoundData` so answered
+	// rounds can be converted easily.
+	impl<B, V> TryFrom<(RoundId, Round<B, V>)> for RoundData<B, V> {
 		type Error = RoundConversionError;
 
-		fn try_from(r: Round<B, V>) -> Result<Self, Self::Error> {
+		fn try_from((round_id, r): (RoundId, Round<B, V>)) -> Result<Self, Self::Error> {
 			if r.answered_in_round.is_none() || r.answer.is_none() || r.updated_at.is_none() {
 				return Err(RoundConversionError::MissingField);
 			}
 			Ok(Self {
+				round_id,
 				started_at: r.started_at,
 				answer: r.answer.unwrap(),
 				updated_at: r.updated_at.unwrap(),
@@ -233,11 +439,37 @@ pub mod pallet {
 		}
 	}
 
+	impl<B: BaseArithmetic + Copy, V> RoundData<B, V> {
+		/// Returns the number of blocks since the data was updated.
+		///
+		/// Saturates at zero if `current_block` is before `updated_at`.
+		pub fn age_in_blocks(&self, current_block: B) -> B {
+			current_block.saturating_sub(self.updated_at)
+		}
+
+		/// Returns `true` if the data was updated at most `max_age` blocks before
+		/// `current_block`.
+		pub fn is_fresh(&self, current_block: B, max_age: B) -> bool {
+			self.age_in_blocks(current_block) <= max_age
+		}
+	}
+
+	/// Returns `true` if `data` was updated at most `max_age` blocks before `current_block`.
+	///
+	/// Same as `RoundData::is_fresh`.
+	pub fn is_fresh<BlockNumber: BaseArithmetic + Copy, Value>(
+		data: &RoundData<BlockNumber, Value>,
+		current_block: BlockNumber,
+		max_age: BlockNumber,
+	) -> bool {
+		data.is_fresh(current_block, max_age)
+	}
+
 	/// Trait for interacting with the feeds in the pallet.
 	pub trait FeedOracle<T: frame_system::Config> {
 		type FeedId: Parameter + BaseArithmetic;
-		type Feed: FeedInterface<T>;
-		type MutableFeed: MutableFeedInterface<T>;
+		type Feed: FeedInterface<T, FeedId = Self::FeedId>;
+		type MutableFeed: MutableFeedInterface<T, FeedId = Self::FeedId>;
 
 		/// Return the read-only interface for the given feed.
 		///
@@ -248,11 +480,40 @@ pub mod pallet {
 		///
 		/// Returns `None` if the feed does not exist.
 		fn feed_mut(id: Self::FeedId) -> Option<Self::MutableFeed>;
+
+		/// Returns `true` if the feed exists and has completed at least one round.
+		fn is_valid_feed(id: Self::FeedId) -> bool;
+
+		/// Return the ids of all existing feeds.
+		///
+		/// The order is unspecified.
+		fn all_feed_ids() -> Box<dyn Iterator<Item = Self::FeedId>>;
+
+		/// The account to pass to `request_new_round` for rounds that are not started by a
+		/// user (e.g. by a hook), so the `NewRound` event does not show an arbitrary account.
+		fn system_account() -> T::AccountId;
+
+		/// Record `value` as the answer of the given round, bypassing the oracle submissions.
+		///
+		/// **Note:** Only available for tests (and with the `testing-utils` feature), never
+		/// in production builds.
+		#[cfg(any(test, feature = "testing-utils"))]
+		fn set_answer(
+			feed_id: Self::FeedId,
+			round_id: RoundId,
+			value: <Self::Feed as FeedInterface<T>>::Value,
+		) -> DispatchResult;
 	}
 
 	/// Trait for read-only access to a feed.
 	pub trait FeedInterface<T: frame_system::Config> {
+		type FeedId: Parameter + Copy;
 		type Value: Parameter + BaseArithmetic;
+		/// The balance type of the oracle payments.
+		type Balance: Parameter;
+
+		/// Returns the id of the feed.
+		fn id(&self) -> Self::FeedId;
 
 		/// Returns the id of the first round that contains non-default data.
 		///
@@ -262,10 +523,44 @@ pub mod pallet {
 		/// Returns the id of the latest oracle round.
 		fn latest_round(&self) -> RoundId;
 
+		/// Returns `true` if the feed contains non-default data.
+		fn has_valid_data(&self) -> bool {
+			self.first_valid_round().is_some()
+		}
+
 		/// Returns the data for a given round.
 		///
-		/// Will return `None` if there is no data for the given round.
-		fn data_at(&self, round: RoundId) -> Option<RoundData<T::BlockNumber, Self::Value>>;
+		/// Will return an error if there is no data for the given round (e.g. it has not
+		/// been answered, yet, or it is before the first valid round).
+		fn data_at(
+			&self,
+			round: RoundId,
+		) -> Result<RoundData<T::BlockNumber, Self::Value>, DispatchError>;
+
+		/// Returns the data of all rounds in `[start, end]` that `data_at` would return.
+		///
+		/// Pruned and unanswered rounds are skipped. Reads every round in the range,
+		/// so this is O(end - start) and callers should limit the window.
+		fn data_range(
+			&self,
+			start: RoundId,
+			end: RoundId,
+		) -> Vec<(RoundId, RoundData<T::BlockNumber, Self::Value>)> {
+			(start..=end)
+				.filter_map(|round| self.data_at(round).ok().map(|data| (round, data)))
+				.collect()
+		}
+
+		/// Returns the answers of the rounds from `since_round` to `latest_round` in
+		/// ascending order.
+		///
+		/// Rounds without data are skipped. This is O(latest_round - since_round),
+		/// so callers should bound the window (e.g. to the pruning window of the feed).
+		fn answers_since(&self, since_round: RoundId) -> Vec<Self::Value> {
+			(since_round..=self.latest_round())
+				.filter_map(|round| self.data_at(round).ok().map(|data| data.answer))
+				.collect()
+		}
 
 		/// Returns the latest data for the feed.
 		///
@@ -274,8 +569,75 @@ pub mod pallet {
 		/// Check `first_valid_round` to determine whether there is useful data, yet.
 		fn latest_data(&self) -> RoundData<T::BlockNumber, Self::Value>;
 
+		/// Returns the latest data for the feed or why it is not available.
+		///
+		/// Unlike `latest_data`, this never returns default data.
+		fn try_get_latest_data(&self) -> Result<RoundData<T::BlockNumber, Self::Value>, FeedError> {
+			if !self.has_valid_data() {
+				return Err(FeedError::NoDataYet);
+			}
+			self.data_at(self.latest_round())
+				.map_err(|_| FeedError::PrunedRound)
+		}
+
+		/// Returns the latest answer of the feed.
+		///
+		/// Returns `None` if there has not been a valid round, yet.
+		fn latest_answer(&self) -> Option<Self::Value> {
+			if self.has_valid_data() {
+				Some(self.latest_data().answer)
+			} else {
+				None
+			}
+		}
+
+		/// Returns `true` if the latest answer is at most `max_age_blocks` old.
+		///
+		/// Always `false` if there has not been a valid round, yet.
+		fn is_current_data_fresh(&self, max_age_blocks: T::BlockNumber) -> bool {
+			self.has_valid_data()
+				&& is_fresh(
+					&self.latest_data(),
+					frame_system::Pallet::<T>::block_number(),
+					max_age_blocks,
+				)
+		}
+
 		/// Represents the number of decimals with which the feed is configured
 		fn decimals(&self) -> u8;
+
+		/// Returns the number of blocks since the feed was created.
+		fn feed_age(&self) -> T::BlockNumber;
+
+		/// Returns the standard deviation of the submissions recorded for the given round.
+		///
+		/// Only available while the round details are still stored (i.e. the round
+		/// accepts submissions). Useful to detect oracle disagreement before a round closes.
+		fn submission_std_dev(&self, round: RoundId) -> Option<Self::Value>;
+
+		/// Returns the status of `oracle` on the feed.
+		///
+		/// Returns `None` if the oracle was never enabled on the feed. Disabled oracles
+		/// have their `ending_round` set.
+		fn oracle_status(
+			&self,
+			oracle: &T::AccountId,
+		) -> Option<OracleStatus<T::BlockNumber, Self::Value, Self::Balance>>;
+
+		/// Returns the average answer of the latest `window` answered rounds.
+		///
+		/// Rounds without their own answer (e.g. timed out or pruned rounds) are skipped.
+		/// Returns `None` if there is no answered round in the window.
+		fn moving_average(&self, window: RoundId) -> Option<Self::Value> {
+			let first = self.first_valid_round()?;
+			let answers: Vec<Self::Value> = (first..=self.latest_round())
+				.rev()
+				.filter_map(|r| self.data_at(r).ok().filter(|d| d.answered_in_round == r))
+				.take(window as usize)
+				.map(|d| d.answer)
+				.collect();
+			compute_moving_average(&answers)
+		}
 	}
 
 	/// Trait for read-write access to a feed.
@@ -309,21 +671,100 @@ pub mod pallet {
 		/// Maximum allowed string length.
 		type StringLimit: Get<u32>;
 
+		/// Maximum length of a single feed tag.
+		type TagLimit: Get<u32>;
+
+		/// Maximum number of tags per feed.
+		type MaxTags: Get<u32>;
+
 		/// Maximum number of oracles per feed.
 		type OracleCountLimit: Get<u32>;
 
 		/// Maximum number of feeds.
+		///
+		/// `create_feed` fails with `FeedLimitReached` once `FeedCounter` reaches this value.
+		#[pallet::constant]
 		type FeedLimit: Get<Self::FeedId>;
 
+		/// Number of blocks after which a feed without a new answer is considered stale.
+		/// Only used by the off-chain worker to log warnings.
+		type StalenessThreshold: Get<Self::BlockNumber>;
+
+		/// Number of submissions kept per oracle and feed in `SubmissionHistory`.
+		type HistoryDepth: Get<u32>;
+
+		/// The minimum number of rounds a requester has to wait between starting rounds.
+		///
+		/// Prevents requesters from superseding rounds before oracles could answer them.
+		#[pallet::constant]
+		type MinRequesterDelay: Get<RoundId>;
+
+		/// The maximum number of new feeds for which `on_initialize` starts the first round
+		/// per block.
+		///
+		/// Remaining feeds are handled in the following blocks.
+		#[pallet::constant]
+		type MaxInitializationsPerBlock: Get<u32>;
+
+		/// The maximum value any oracle can submit, regardless of the feed bounds.
+		///
+		/// Feeds cannot be configured with value bounds above this value.
+		#[pallet::constant]
+		type MaxSubmissionValue: Get<Self::Value>;
+
+		/// The account receiving the treasury share of oracle payments.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The reward paid from the fund account for closing a timed out round via
+		/// `close_timed_out_round`.
+		///
+		/// Should be at least the existential deposit so new accounts can receive it.
+		#[pallet::constant]
+		type TimeoutCleanupReward: Get<BalanceOf<Self>>;
+
+		/// Source of the current Unix time (e.g. the timestamp pallet).
+		///
+		/// Only used to approximate the time of past blocks for `ChainlinkAggregatorV3`.
+		type UnixTime: UnixTime;
+
+		/// The expected time between two blocks in milliseconds.
+		#[pallet::constant]
+		type ExpectedBlockTime: Get<u64>;
+
+		/// The origin that can set the pallet admin directly (e.g. `EnsureRoot`).
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
+
 		/// This callback will trigger when the round answer updates
 		type OnAnswerHandler: OnAnswerHandler<Self>;
 
+		/// Validates aggregated answers before they are stored. `()` accepts all answers.
+		type AnswerValidator: AnswerValidator<Self>;
+
+		/// The public key identifying oracles that use unsigned submissions.
+		type Public: IdentifyAccount<AccountId = Self::AccountId> + Parameter;
+
+		/// The signature over unsigned oracle submissions.
+		type Signature: Verify<Signer = Self::Public> + Parameter;
+
+		/// The priority of unsigned oracle submissions in the transaction pool.
+		type UnsignedPriority: Get<TransactionPriority>;
+
 		/// The weight for this pallet's extrinsics.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The maximum number of items returned by the paginated queries (e.g. `paginated_rounds`).
+	///
+	/// Larger `count`s are clamped, as the queries are reachable from the runtime API.
+	pub const MAX_PAGE_SIZE: u32 = 100;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -343,10 +784,37 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn feed_config)]
-	/// A running counter used internally to determine the next feed id.
+	/// The configs of the feeds.
+	///
+	/// `Twox64Concat` is safe for the keys because feed ids are assigned sequentially by
+	/// `create_feed` and cannot be chosen by users. Compare the cost with
+	/// `Blake2_128Concat` via the `feed_key_hashers` test in `benchmarking.rs` before
+	/// switching hashers.
 	pub type Feeds<T: Config> =
 		StorageMap<_, Twox64Concat, T::FeedId, FeedConfigOf<T>, OptionQuery>;
 
+	#[pallet::storage]
+	/// Index of the feeds by the hash of their description.
+	///
+	/// Stores the most recently created feed if several feeds share the same description.
+	pub type FeedsByDescription<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, T::FeedId, OptionQuery>;
+
+	#[pallet::storage]
+	/// Index of the feeds by their tags.
+	pub type FeedsByTag<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, TagOf<T>, Twox64Concat, T::FeedId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn uninitialized_feeds)]
+	/// Feeds waiting for `on_initialize` to start their first round.
+	pub type UninitializedFeeds<T: Config> = StorageValue<_, Vec<T::FeedId>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reserve_low_warned)]
+	/// Whether `ReserveLow` was emitted since the reserve last dropped below the threshold.
+	pub type ReserveLowWarned<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::storage]
 	/// Accounts allowed to create feeds.
 	pub type FeedCreators<T: Config> =
@@ -355,6 +823,9 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn round)]
 	/// User-facing round data.
+	///
+	/// Like `Feeds`, the keys are assigned sequentially by the pallet, so `Twox64Concat`
+	/// is safe.
 	pub type Rounds<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
@@ -397,6 +868,30 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// The feeds an oracle is enabled on (reverse index of `OracleStatuses`).
+	pub type OracleFeeds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::FeedId,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The latest submissions (newest first) of an oracle for a feed.
+	pub type SubmissionHistory<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::FeedId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::Value, T::HistoryDepth>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn requester)]
 	/// Per-feed permissioning for starting new rounds.
@@ -410,12 +905,28 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn round_payment_override)]
+	/// Payments set for rounds that have not been started, yet.
+	/// Taken when the round is initialized.
+	pub type RoundPaymentOverrides<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::FeedId,
+		Twox64Concat,
+		RoundId,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::metadata(
 		T::AccountId = "AccountId",
 		T::FeedId = "FeedId",
 		T::BlockNumber = "BlockNumber",
 		T::Value = "Value",
+		T::Hash = "Hash",
+		BalanceOf<T> = "Balance",
 		RoundId = "RoundId",
 		SubmissionBounds = "SubmissionBounds"
 	)]
@@ -423,12 +934,19 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// A new oracle feed was created. \[feed_id, creator\]
 		FeedCreated(T::FeedId, T::AccountId),
+		/// The description of the feed is already used by another feed which is no longer
+		/// found via the description index. \[previous_feed_id, feed_id, description_hash\]
+		DuplicateDescription(T::FeedId, T::FeedId, T::Hash),
 		/// A new round was started. \[new_round_id, initiator, started_at\]
 		NewRound(T::FeedId, RoundId, T::AccountId, T::BlockNumber),
 		/// A submission was recorded. \[feed_id, round_id, submission, oracle\]
 		SubmissionReceived(T::FeedId, RoundId, T::Value, T::AccountId),
 		/// The answer for the round was updated. \[feed_id, round_id, new_answer, updated_at_block\]
 		AnswerUpdated(T::FeedId, RoundId, T::Value, T::BlockNumber),
+		/// The new answer deviates from the previous one by more than the configured threshold.
+		/// Not emitted again for later submissions to the round that keep deviating.
+		/// \[feed_id, round_id, previous_answer, new_answer, threshold_percent\]
+		AnswerDeviationHigh(T::FeedId, RoundId, T::Value, T::Value, u8),
 		/// The round details were updated. \[feed_id, payment, submission_count_bounds, restart_delay, timeout\]
 		RoundDetailsUpdated(
 			T::FeedId,
@@ -457,6 +975,52 @@ pub mod pallet {
 		FeedCreator(T::AccountId),
 		/// The account is no longer allowed to create feeds. \[previously_creator\]
 		FeedCreatorRemoved(T::AccountId),
+		/// The rounds outside the pruning window of the feed have been removed. \[feed, pruned_rounds\]
+		PruningCompleted(T::FeedId, RoundId),
+		/// The payment of a single round was overridden. \[feed_id, round_id, payment\]
+		RoundPaymentSet(T::FeedId, RoundId, BalanceOf<T>),
+		/// A round timed out without any submissions and was closed. \[feed_id, round_id\]
+		RoundTimedOut(T::FeedId, RoundId),
+		/// A round timed out with too few submissions to be answered and was closed.
+		/// \[feed_id, round_id, submissions\]
+		RoundAborted(T::FeedId, RoundId, u32),
+		/// The pallet admin overrode the answer of a round.
+		/// \[feed_id, round_id, answer, pallet_admin\]
+		ForcedAnswer(T::FeedId, RoundId, T::Value, T::AccountId),
+		/// The pending pallet admin transfer was cancelled. \[cancelled_pallet_admin\]
+		PalletAdminTransferCancelled(T::AccountId),
+		/// The minimum oracle count of the feed was updated. \[feed_id, min_oracle_count\]
+		MinOracleCountSet(T::FeedId, u32),
+		/// The tags of the feed were updated. \[feed_id\]
+		TagsSet(T::FeedId),
+		/// The oracle payment of the feed was updated. \[feed_id, payment\]
+		PaymentAmountUpdated(T::FeedId, BalanceOf<T>),
+		/// The round timeout of the feed was updated. \[feed_id, timeout\]
+		TimeoutUpdated(T::FeedId, T::BlockNumber),
+		/// A round was answered with the same answer as the previous round.
+		/// Emitted by `submit` instead of `AnswerUpdated`, once per round unless the answer
+		/// changes in between. \[feed_id, round_id, answer, updated_at_block\]
+		AnswerUnchanged(T::FeedId, RoundId, T::Value, T::BlockNumber),
+		/// The pallet admin replaced the owner of the feed. \[feed_id, old_owner, new_owner\]
+		OwnerOverridden(T::FeedId, T::AccountId, T::AccountId),
+		/// The feed was marked as end-of-life. \[feed_id, by\]
+		FeedDeprecated(T::FeedId, T::AccountId),
+		/// The feed is no longer marked as end-of-life. \[feed_id, by\]
+		FeedUndeprecated(T::FeedId, T::AccountId),
+		/// The free balance of the fund account dropped below twice the minimum reserve.
+		/// Emitted once until the fund is topped up again. \[current, minimum\]
+		ReserveLow(BalanceOf<T>, BalanceOf<T>),
+		/// All submissions of an answered round are identical, which may indicate that the
+		/// oracles are not independent. Emitted once per round. Advisory only, the answer is
+		/// recorded as usual.
+		/// \[feed_id, round_id, value, submission_count\]
+		OracleCollusionSuspected(T::FeedId, RoundId, T::Value, u32),
+		/// The last oracle of a feed was disabled and the oracle statuses of the feed were
+		/// removed. \[feed_id\]
+		AllOraclesRemoved(T::FeedId),
+		/// The owner closed a round before the maximum number of submissions was reached.
+		/// \[feed_id, round_id\]
+		RoundClosed(T::FeedId, RoundId),
 		#[cfg(test)]
 		/// New round data
 		///
@@ -543,17 +1107,166 @@ pub mod pallet {
 		NotFeedCreator,
 		/// The maximum debt of feeds was reached.
 		MaxDebtReached,
+		/// The round is within the pruning window or was already pruned.
+		NothingToPrune,
+		/// The round already received submissions.
+		RoundHasSubmissions,
+		/// The requester delay is below the configured minimum.
+		RequesterDelayTooSmall,
+		/// The treasury share cannot exceed 100 percent.
+		TreasuryPercentTooHigh,
+		/// The round was already closed with an answer.
+		RoundAlreadyAnswered,
+		/// The round has fewer submissions than required for an answer.
+		NotEnoughSubmissions,
+		/// The feed already has the maximum number of open rounds.
+		TooManyOpenRounds,
+		/// There is no pending pallet admin transfer.
+		NoPendingPalletAdmin,
+		/// The submission exceeds the maximum value allowed for all feeds.
+		SubmissionExceedsGlobalMax,
+		/// The feed would have fewer oracles than its minimum oracle count.
+		BelowMinimumOracleCount,
+		/// A tag is longer than `TagLimit`.
+		TagTooLong,
+		/// More tags than `MaxTags` were provided.
+		TooManyTags,
+		/// Feeds need at least one submission per round.
+		ZeroMinSubmissions,
+		/// The round was closed and does not accept submissions anymore.
+		RoundAlreadyClosed,
+		/// The round was not started, yet.
+		RoundNotInitialized,
+		/// The runtime was built without the `admin-override` feature.
+		OwnerOverrideDisabled,
+		/// The submission deadline of the round has passed.
+		SubmissionDeadlineExceeded,
+		/// The feed was deprecated and does not accept submissions.
+		FeedDeprecated,
+		/// The round has not timed out (or was already closed).
+		RoundNotTimedOut,
+		/// The witness data does not cover the work done by the call.
+		InvalidWitness,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Start the first round of up to `MaxInitializationsPerBlock` newly created feeds.
+		///
+		/// Feeds that already started a round (e.g. because an oracle submitted) or that were
+		/// removed in the meantime are skipped.
+		fn on_initialize(_block_number: T::BlockNumber) -> Weight {
+			// the reserve check in `on_finalize`
+			let finalize = T::DbWeight::get().reads_writes(2, 1);
+			let mut pending = UninitializedFeeds::<T>::get();
+			if pending.is_empty() {
+				return T::DbWeight::get().reads(1).saturating_add(finalize);
+			}
+			let count = pending
+				.len()
+				.min(T::MaxInitializationsPerBlock::get() as usize);
+			let started_by = <Self as FeedOracle<T>>::system_account();
+			for feed_id in pending.drain(..count) {
+				let res = with_transaction(|| {
+					let res = match Feed::<T>::load_from(feed_id) {
+						Some(mut feed) if feed.reporting_round_id().is_zero() => {
+							feed.request_new_round(started_by.clone())
+						}
+						_ => Ok(()),
+					};
+					if res.is_ok() {
+						TransactionOutcome::Commit(res)
+					} else {
+						TransactionOutcome::Rollback(res)
+					}
+				});
+				if let Err(e) = res {
+					log::warn!(
+						target: "runtime::chainlink-feed",
+						"could not start the first round of feed {:?}: {:?}",
+						feed_id,
+						e,
+					);
+				}
+			}
+			UninitializedFeeds::<T>::put(pending);
+			// loading the feed and starting the round
+			let per_feed = T::DbWeight::get().reads_writes(4, 4);
+			T::DbWeight::get()
+				.reads_writes(1, 1)
+				.saturating_add(per_feed.saturating_mul(count as Weight))
+				.saturating_add(finalize)
+		}
+
+		/// Emit `ReserveLow` once the fund account holds less than twice the minimum reserve.
+		fn on_finalize(_block_number: T::BlockNumber) {
+			let reserve = Self::reserve_balance();
+			let minimum = T::MinimumReserve::get();
+			let is_low = reserve < minimum.saturating_mul(2u32.into());
+			if is_low == Self::reserve_low_warned() {
+				return;
+			}
+			if is_low {
+				Self::deposit_event(Event::ReserveLow(reserve, minimum));
+			}
+			ReserveLowWarned::<T>::put(is_low);
+		}
+
+		/// Log a warning for every feed that has not been updated within `StalenessThreshold`.
+		///
+		/// Does not modify any state.
+		fn offchain_worker(block_number: T::BlockNumber) {
+			for (feed_id, staleness) in Self::stale_feeds(block_number) {
+				log::warn!(
+					target: "runtime::chainlink-feed",
+					"feed {:?} has not been updated for {:?} blocks",
+					feed_id,
+					staleness,
+				);
+			}
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::MigrateToV1::<T>::on_runtime_upgrade()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			// the old format cannot be checked for consistency
+			match StorageVersion::get::<Pallet<T>>() {
+				v if v < 1 => crate::migrations::v1::MigrateToV1::<T>::pre_upgrade(),
+				_ => Self::check_storage_consistency(),
+			}
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			crate::migrations::v1::MigrateToV1::<T>::post_upgrade()?;
+			Self::check_storage_consistency()
+		}
+	}
 
+	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
-		/// Shortcut for getting account ID
-		fn account_id() -> T::AccountId {
+		/// The account holding the funds used to pay the oracles.
+		pub fn fund_account() -> T::AccountId {
+			Self::fund_account_id()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding the funds used to pay the oracles.
+		///
+		/// Derived from the configured `PalletId`.
+		pub fn fund_account_id() -> T::AccountId {
 			T::PalletId::get().into_account()
 		}
 
+		/// The free balance of the fund account.
+		pub fn reserve_balance() -> BalanceOf<T> {
+			T::Currency::free_balance(&Self::fund_account_id())
+		}
+
 		/// Get debt by FeedId
 		pub fn debt(feed_id: T::FeedId) -> Result<BalanceOf<T>, Error<T>> {
 			if let Some(feed_config) = <Feeds<T>>::get(feed_id) {
@@ -562,104 +1275,798 @@ pub mod pallet {
 				Err(<Error<T>>::FeedNotFound)
 			}
 		}
-	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		// --- feed operations ---
+		/// Return the latest submissions (newest first) of `oracle` for the given feed.
+		pub fn submission_history(feed_id: T::FeedId, oracle: &T::AccountId) -> Vec<T::Value> {
+			SubmissionHistory::<T>::get(feed_id, oracle).into_inner()
+		}
 
-		/// Create a new oracle feed with the given config values.
-		/// Limited to feed creator accounts.
-		#[pallet::weight(T::WeightInfo::create_feed(oracles.len() as u32))]
-		#[allow(clippy::too_many_arguments)]
-		pub fn create_feed(
-			origin: OriginFor<T>,
-			payment: BalanceOf<T>,
-			timeout: T::BlockNumber,
-			submission_value_bounds: (T::Value, T::Value),
-			min_submissions: u32,
-			decimals: u8,
-			description: Vec<u8>,
-			restart_delay: RoundId,
-			oracles: Vec<(T::AccountId, T::AccountId)>,
-			pruning_window: Option<RoundId>,
-			max_debt: Option<BalanceOf<T>>,
-		) -> DispatchResultWithPostInfo {
-			let owner = ensure_signed(origin)?;
-			ensure!(
-				FeedCreators::<T>::contains_key(&owner),
-				Error::<T>::NotFeedCreator
-			);
-			ensure!(
-				description.len() as u32 <= T::StringLimit::get(),
-				Error::<T>::DescriptionTooLong
-			);
+		/// Return the number of oracles of the feed that are not disabled.
+		///
+		/// Counted from the oracle statuses instead of using the cached `oracle_count`.
+		pub fn active_oracle_count(feed_id: T::FeedId) -> u32 {
+			let reporting_round = Self::feed_config(feed_id)
+				.map(|config| config.reporting_round)
+				.unwrap_or_else(Zero::zero);
+			Self::count_active_oracles(feed_id, reporting_round)
+		}
 
-			let pruning_window = pruning_window.unwrap_or(RoundId::MAX);
-			ensure!(
-				pruning_window > RoundId::zero(),
-				Error::<T>::CannotPruneRoundZero
-			);
+		fn count_active_oracles(feed_id: T::FeedId, reporting_round: RoundId) -> u32 {
+			Self::oracle_statuses_for_feed(feed_id)
+				.filter(|(_, status)| status.ending_round.map_or(true, |e| e > reporting_round))
+				.count() as u32
+		}
 
-			let submission_count_bounds = (min_submissions, oracles.len() as u32);
+		/// Iterate over all oracles of the feed together with their status.
+		///
+		/// Includes disabled oracles, the order is unspecified.
+		pub fn oracle_statuses_for_feed(
+			feed_id: T::FeedId,
+		) -> impl Iterator<Item = (T::AccountId, OracleStatusOf<T>)> {
+			OracleStatuses::<T>::iter_prefix(feed_id)
+		}
 
-			with_transaction_result(|| -> DispatchResultWithPostInfo {
-				let id: T::FeedId = FeedCounter::<T>::get();
-				ensure!(id < T::FeedLimit::get(), Error::<T>::FeedLimitReached);
-				let new_id = id.checked_add(&One::one()).ok_or(Error::<T>::Overflow)?;
-				FeedCounter::<T>::put(new_id);
-
-				let new_config = FeedConfig {
-					owner: owner.clone(),
-					pending_owner: None,
-					payment,
-					timeout,
-					submission_value_bounds,
-					submission_count_bounds,
-					decimals,
-					description,
-					restart_delay,
-					latest_round: Zero::zero(),
-					reporting_round: Zero::zero(),
-					first_valid_round: None,
-					oracle_count: Zero::zero(),
-					pruning_window,
-					next_round_to_prune: RoundId::one(),
-					debt: Zero::zero(),
-					max_debt,
-				};
-				let mut feed = Feed::<T>::new(id, new_config); // synced on drop
-				let started_at = frame_system::Pallet::<T>::block_number();
-				let updated_at = Some(started_at);
-				// Store a dummy value for round 0 because we will not get useful data for
-				// it, but need some seed data that future rounds can carry over.
-				Rounds::<T>::insert(
-					id,
-					RoundId::zero(),
-					Round {
-						started_at,
-						answer: Some(Zero::zero()),
-						updated_at,
-						answered_in_round: Some(Zero::zero()),
-					},
-				);
-				feed.add_oracles(oracles)?;
-				// validate the rounds config
-				feed.update_future_rounds(
-					payment,
-					submission_count_bounds,
-					restart_delay,
-					timeout,
-				)?;
-				Self::deposit_event(Event::FeedCreated(id, owner));
-				Ok(().into())
-			})
+		/// Return how many blocks after the start of the round the oracle submitted its
+		/// latest answer.
+		///
+		/// Returns `None` if the oracle has not submitted yet (or the round was pruned).
+		pub fn oracle_response_time(
+			feed_id: T::FeedId,
+			oracle: &T::AccountId,
+		) -> Option<T::BlockNumber> {
+			let status = Self::oracle_status(feed_id, oracle)?;
+			let reported_at = status.last_reported_block?;
+			let started_at = Self::round(feed_id, status.last_reported_round?)?.started_at;
+			Some(reported_at.saturating_sub(started_at))
 		}
 
-		/// Initiate the transfer of the feed to `new_owner`.
-		#[pallet::weight(T::WeightInfo::transfer_ownership())]
-		pub fn transfer_ownership(
-			origin: OriginFor<T>,
+		/// Return the number of submissions the oracle has made to the feed.
+		///
+		/// Submissions made before the count was introduced are not included.
+		/// Returns `None` if the oracle was never enabled on the feed.
+		pub fn lifetime_submissions(feed_id: T::FeedId, oracle: &T::AccountId) -> Option<u32> {
+			Self::oracle_status(feed_id, oracle).map(|status| status.submission_count)
+		}
+
+		/// Return the sum of the payments the oracle received for submissions to the feed.
+		///
+		/// Payments made before the sum was introduced are not included.
+		/// Returns `None` if the oracle was never enabled on the feed.
+		pub fn oracle_feed_earnings(
+			feed_id: T::FeedId,
+			oracle: &T::AccountId,
+		) -> Option<BalanceOf<T>> {
+			Self::oracle_status(feed_id, oracle).map(|status| status.cumulative_earnings)
+		}
+
+		/// Return the feeds the oracle is enabled on.
+		///
+		/// The order is unspecified.
+		pub fn feeds_for_oracle(oracle: &T::AccountId) -> Vec<T::FeedId> {
+			OracleFeeds::<T>::iter_prefix(oracle)
+				.map(|(feed_id, _)| feed_id)
+				.collect()
+		}
+
+		/// Return the sum of the withdrawable payments of all oracles administered by
+		/// `admin`.
+		///
+		/// **Note:** Iterates over all oracles, i.e. is O(n) in the number of oracles.
+		/// Only meant to be used off-chain (e.g. via the runtime API).
+		pub fn oracle_earnings(admin: &T::AccountId) -> BalanceOf<T> {
+			Oracles::<T>::iter_values()
+				.filter(|meta| meta.admin == *admin)
+				.fold(Zero::zero(), |total: BalanceOf<T>, meta| {
+					total.saturating_add(meta.withdrawable)
+				})
+		}
+
+		/// Return all oracles administered by `admin`.
+		///
+		/// **Note:** Iterates over all oracles, i.e. is O(n) in the number of oracles.
+		/// Only meant to be used off-chain (e.g. via the runtime API).
+		pub fn oracle_addresses_for_admin(admin: &T::AccountId) -> Vec<T::AccountId> {
+			Oracles::<T>::iter()
+				.filter(|(_, meta)| meta.admin == *admin)
+				.map(|(oracle, _)| oracle)
+				.collect()
+		}
+
+		/// Return all accounts allowed to request new rounds on the feed together with
+		/// their requester permissions.
+		///
+		/// The order is unspecified.
+		pub fn list_requesters(feed_id: T::FeedId) -> Vec<(T::AccountId, Requester)> {
+			Requesters::<T>::iter_prefix(feed_id).collect()
+		}
+
+		/// Return the number of submissions the round has received.
+		///
+		/// Returns `None` if the round does not accept submissions (anymore).
+		pub fn round_submission_count(feed_id: T::FeedId, round_id: RoundId) -> Option<u32> {
+			Self::round_details(feed_id, round_id).map(|d| d.submissions.len() as u32)
+		}
+
+		/// Capture the current state of the feed for debugging purposes.
+		///
+		/// Returns `None` if the feed does not exist.
+		pub fn snapshot(feed_id: T::FeedId) -> Option<FeedSnapshot<T>> {
+			let config = Self::feed_config(feed_id)?;
+			// the latest round is never pruned
+			let latest_round = Self::round(feed_id, config.latest_round).unwrap_or_default();
+			let latest_details = Self::round_details(feed_id, config.reporting_round);
+			let active_oracle_count = Self::count_active_oracles(feed_id, config.reporting_round);
+			Some(FeedSnapshot {
+				config,
+				latest_round,
+				latest_details,
+				active_oracle_count,
+			})
+		}
+
+		/// Return the snapshots of up to `count` feeds, starting at the feed id `start`.
+		///
+		/// Ids without a stored feed are skipped.
+		pub fn paginated_snapshots(start: T::FeedId, count: u32) -> Vec<FeedSnapshot<T>> {
+			Self::paginated_feeds(start, count, Self::snapshot)
+		}
+
+		/// Return the summary of the feed.
+		///
+		/// Returns `None` if the feed does not exist.
+		pub fn summary(feed_id: T::FeedId) -> Option<FeedSummaryOf<T>> {
+			let config = Self::feed_config(feed_id)?;
+			// the seed round does not contain an answer
+			let latest_round = config
+				.first_valid_round
+				.and_then(|_| Self::round(feed_id, config.latest_round))
+				.unwrap_or_default();
+			Some(FeedSummary {
+				id: feed_id,
+				description_hash: T::Hashing::hash_of(&config.description),
+				latest_answer: latest_round.answer,
+				updated_at: latest_round.updated_at,
+				decimals: config.decimals,
+			})
+		}
+
+		/// Return the summaries of up to `count` feeds, starting at the feed id `start`.
+		///
+		/// Ids without a stored feed are skipped.
+		pub fn paginated_summaries(start: T::FeedId, count: u32) -> Vec<FeedSummaryOf<T>> {
+			Self::paginated_feeds(start, count, Self::summary)
+		}
+
+		/// Return the answer of the feed that was current at the given block.
+		///
+		/// That is the answer of the latest round updated at or before `block`. Returns
+		/// `None` if there is no such round or it was already pruned.
+		///
+		/// **Note:** Walks back from the latest round, i.e. is O(n) in the number of rounds
+		/// since `block`. Only meant to be used off-chain (e.g. via the runtime API).
+		pub fn value_at_block(feed_id: T::FeedId, block: T::BlockNumber) -> Option<T::Value> {
+			let config = Self::feed_config(feed_id)?;
+			// the seed round does not contain an answer
+			let first = config.first_valid_round?;
+			for round_id in (first..=config.latest_round).rev() {
+				// stop at pruned rounds
+				let round = Self::round(feed_id, round_id)?;
+				match (round.answer, round.updated_at) {
+					(Some(answer), Some(updated_at)) if updated_at <= block => return Some(answer),
+					_ => continue,
+				}
+			}
+			None
+		}
+
+		/// Approximate the Unix time (in seconds) at which the given block was produced.
+		///
+		/// Assumes that all blocks since then were produced at `ExpectedBlockTime`.
+		/// Future blocks are mapped to the current time.
+		pub fn block_to_unix_seconds(block: T::BlockNumber) -> u64 {
+			let now: u64 = T::UnixTime::now().as_millis().unique_saturated_into();
+			let current: u64 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+			let block: u64 = block.unique_saturated_into();
+			let elapsed = current
+				.saturating_sub(block)
+				.saturating_mul(T::ExpectedBlockTime::get());
+			now.saturating_sub(elapsed) / 1000
+		}
+
+		/// Collect the results of `f` for up to `count` feeds, starting at the feed id `start`.
+		///
+		/// `count` is clamped to `MAX_PAGE_SIZE`.
+		fn paginated_feeds<R>(
+			start: T::FeedId,
+			count: u32,
+			f: impl Fn(T::FeedId) -> Option<R>,
+		) -> Vec<R> {
+			let count = count.min(MAX_PAGE_SIZE);
+			let end = FeedCounter::<T>::get();
+			let mut results = Vec::new();
+			let mut id = start;
+			while id < end && (results.len() as u32) < count {
+				results.extend(f(id));
+				id = match id.checked_add(&One::one()) {
+					Some(next) => next,
+					None => break,
+				};
+			}
+			results
+		}
+
+		/// Return the stored rounds of the feed in `start..start + count` in ascending order.
+		///
+		/// Rounds that are not in storage (e.g. pruned ones) are skipped. Reads the rounds
+		/// individually instead of iterating the whole feed prefix, so `count` is clamped to
+		/// `MAX_PAGE_SIZE`.
+		pub fn paginated_rounds(
+			feed_id: T::FeedId,
+			start: RoundId,
+			count: u32,
+		) -> Vec<(RoundId, RoundOf<T>)> {
+			let count = count.min(MAX_PAGE_SIZE);
+			(start..start.saturating_add(count))
+				.filter_map(|id| Rounds::<T>::get(feed_id, id).map(|round| (id, round)))
+				.collect()
+		}
+
+		/// Return the id of the most recently created feed with the given description.
+		pub fn feed_id_for_description(description: &[u8]) -> Option<T::FeedId> {
+			FeedsByDescription::<T>::get(T::Hashing::hash_of(&description))
+		}
+
+		/// Return the ids of all feeds with the given tag.
+		pub fn feeds_with_tag(tag: &[u8]) -> Vec<T::FeedId> {
+			match TagOf::<T>::try_from(tag.to_vec()) {
+				Ok(tag) => FeedsByTag::<T>::iter_prefix(tag)
+					.map(|(id, _)| id)
+					.collect(),
+				Err(_) => Vec::new(),
+			}
+		}
+
+		/// Convert the tags into their bounded representation.
+		fn bounded_tags(tags: Vec<Vec<u8>>) -> Result<BoundedVec<TagOf<T>, T::MaxTags>, Error<T>> {
+			tags.into_iter()
+				.map(|tag| TagOf::<T>::try_from(tag).map_err(|_| Error::<T>::TagTooLong))
+				.collect::<Result<Vec<_>, _>>()?
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyTags)
+		}
+
+		/// Point the description index to the given feed.
+		///
+		/// Emits `DuplicateDescription` if another feed was indexed for the description.
+		fn index_description(feed_id: T::FeedId, description: &[u8]) {
+			let hash = T::Hashing::hash_of(&description);
+			if let Some(previous) = FeedsByDescription::<T>::get(&hash) {
+				if previous != feed_id {
+					Self::deposit_event(Event::DuplicateDescription(previous, feed_id, hash));
+				}
+			}
+			FeedsByDescription::<T>::insert(&hash, feed_id);
+		}
+
+		/// Return all feeds whose latest answer is older than `StalenessThreshold`
+		/// at block `now` together with the number of blocks since the last update.
+		pub fn stale_feeds(now: T::BlockNumber) -> Vec<(T::FeedId, T::BlockNumber)> {
+			let threshold = T::StalenessThreshold::get();
+			Feeds::<T>::iter()
+				.filter_map(|(feed_id, config)| {
+					let updated_at = Self::round(feed_id, config.latest_round)?.updated_at?;
+					let staleness = now.saturating_sub(updated_at);
+					if staleness > threshold {
+						Some((feed_id, staleness))
+					} else {
+						None
+					}
+				})
+				.collect()
+		}
+
+		/// Return the feeds that have rounds outside of their pruning window
+		/// together with the total number of those rounds.
+		fn expired_feeds() -> (Vec<T::FeedId>, u32) {
+			let mut rounds: u32 = 0;
+			let feeds = Feeds::<T>::iter()
+				.filter_map(|(feed_id, config)| {
+					let window = config
+						.latest_round
+						.saturating_sub(config.next_round_to_prune);
+					let expired = window
+						.saturating_add(1)
+						.saturating_sub(config.pruning_window);
+					rounds = rounds.saturating_add(expired);
+					if expired > 0 {
+						Some(feed_id)
+					} else {
+						None
+					}
+				})
+				.collect();
+			(feeds, rounds)
+		}
+
+		/// Verify the invariants between the storage items of the pallet.
+		///
+		/// Logs every violation and returns an error if there was at least one.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn check_storage_consistency() -> Result<(), &'static str> {
+			const TARGET: &str = "runtime::chainlink-feed";
+			let mut consistent = true;
+
+			let counter = FeedCounter::<T>::get();
+			for (feed_id, config) in Feeds::<T>::iter() {
+				if feed_id >= counter {
+					log::error!(
+						target: TARGET,
+						"feed {:?} is not below the feed counter {:?}",
+						feed_id,
+						counter,
+					);
+					consistent = false;
+				}
+				if config.id != feed_id {
+					log::error!(
+						target: TARGET,
+						"feed {:?} is stored with id {:?}",
+						feed_id,
+						config.id,
+					);
+					consistent = false;
+				}
+				let active = OracleStatuses::<T>::iter_prefix_values(feed_id)
+					.filter(|status| status.ending_round.is_none())
+					.count() as u32;
+				if config.oracle_count != active {
+					log::error!(
+						target: TARGET,
+						"feed {:?} has an oracle count of {} but {} active oracles",
+						feed_id,
+						config.oracle_count,
+						active,
+					);
+					consistent = false;
+				}
+			}
+
+			for (feed_id, oracle, status) in OracleStatuses::<T>::iter() {
+				if !Oracles::<T>::contains_key(&oracle) {
+					log::error!(
+						target: TARGET,
+						"oracle {:?} of feed {:?} has no oracle meta data",
+						oracle,
+						feed_id,
+					);
+					consistent = false;
+				}
+				if status.ending_round.is_none() != OracleFeeds::<T>::contains_key(&oracle, feed_id)
+				{
+					log::error!(
+						target: TARGET,
+						"oracle {:?} of feed {:?} is not indexed correctly",
+						oracle,
+						feed_id,
+					);
+					consistent = false;
+				}
+			}
+
+			for (feed_id, round_id, _) in Details::<T>::iter() {
+				let reporting_round = Feeds::<T>::get(feed_id).map(|config| config.reporting_round);
+				if reporting_round.map(|r| round_id > r).unwrap_or(true) {
+					log::error!(
+						target: TARGET,
+						"round details {} of feed {:?} are beyond the reporting round {:?}",
+						round_id,
+						feed_id,
+						reporting_round,
+					);
+					consistent = false;
+				}
+			}
+
+			if consistent {
+				Ok(())
+			} else {
+				Err("chainlink feed storage is inconsistent")
+			}
+		}
+
+		/// Whether an account that last started `last_started` is allowed to start `new_round`
+		/// given the `delay` in rounds between starts.
+		///
+		/// Used for both oracles (with the feed's `restart_delay`) and requesters.
+		fn may_start_round(
+			last_started: Option<RoundId>,
+			delay: RoundId,
+			new_round: RoundId,
+		) -> Result<bool, DispatchError> {
+			match last_started {
+				None => Ok(true),
+				Some(last_started) => {
+					let next_allowed_round = last_started
+						.checked_add(delay)
+						.ok_or(Error::<T>::Overflow)?;
+					Ok(new_round > next_allowed_round)
+				}
+			}
+		}
+
+		/// Record the `submission` of `oracle` for the given feed and round.
+		///
+		/// Shared implementation of `submit` and `submit_unsigned`.
+		#[transactional]
+		fn do_submit(
+			oracle: T::AccountId,
+			feed_id: T::FeedId,
+			round_id: RoundId,
+			submission: T::Value,
+		) -> DispatchResultWithPostInfo {
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			ensure!(!feed.config.deprecated, Error::<T>::FeedDeprecated);
+			let mut oracle_status =
+				Self::oracle_status(feed_id, &oracle).ok_or(Error::<T>::NotOracle)?;
+			feed.ensure_valid_round(&oracle, round_id)?;
+
+			ensure!(
+				submission <= T::MaxSubmissionValue::get(),
+				Error::<T>::SubmissionExceedsGlobalMax
+			);
+			let (min_val, max_val) = feed.config.submission_value_bounds;
+			ensure!(submission >= min_val, Error::<T>::SubmissionBelowMinimum);
+			ensure!(submission <= max_val, Error::<T>::SubmissionAboveMaximum);
+
+			let new_round_id = feed.reporting_round_id().saturating_add(One::one());
+			// same delay semantics as for requesters
+			let eligible_to_start = Self::may_start_round(
+				oracle_status.last_started_round,
+				feed.config.restart_delay,
+				round_id,
+			)?;
+
+			// initialize the round if conditions are met
+			if round_id == new_round_id && eligible_to_start {
+				let started_at = feed.initialize_round(new_round_id, &oracle)?;
+
+				Self::deposit_event(Event::NewRound(
+					feed_id,
+					new_round_id,
+					oracle.clone(),
+					started_at,
+				));
+
+				oracle_status.last_started_round = Some(new_round_id);
+			}
+
+			// record submission
+			let mut details = match Details::<T>::take(feed_id, round_id) {
+				Some(details) => details,
+				// the details of started rounds are removed once they are closed
+				None if Rounds::<T>::contains_key(feed_id, round_id) => {
+					return Err(Error::<T>::RoundAlreadyClosed.into())
+				}
+				None => return Err(Error::<T>::RoundNotInitialized.into()),
+			};
+			if let Some(deadline) = details.deadline {
+				ensure!(
+					frame_system::Pallet::<T>::block_number() <= deadline,
+					Error::<T>::SubmissionDeadlineExceeded
+				);
+			}
+			details
+				.submissions
+				.try_push(submission)
+				.map_err(|_| Error::<T>::OraclesLimitExceeded)?;
+
+			oracle_status.last_reported_round = Some(round_id);
+			oracle_status.submission_count = oracle_status.submission_count.saturating_add(1);
+			oracle_status.latest_submission = Some(submission);
+			oracle_status.last_reported_block = Some(frame_system::Pallet::<T>::block_number());
+			// the treasury share is deducted from the payment (rounded down)
+			let treasury_share =
+				Percent::from_percent(feed.config.treasury_percent).mul_floor(details.payment);
+			let payment = details.payment.saturating_sub(treasury_share);
+			oracle_status.cumulative_earnings =
+				oracle_status.cumulative_earnings.saturating_add(payment);
+			OracleStatuses::<T>::insert(feed_id, &oracle, oracle_status);
+			SubmissionHistory::<T>::mutate(feed_id, &oracle, |history| {
+				// drop the oldest submission to make room for the new one
+				if !history.is_empty() && history.len() >= T::HistoryDepth::get() as usize {
+					history.remove(history.len() - 1);
+				}
+				// only fails for a history depth of zero
+				let _ = history.try_insert(0, submission);
+			});
+			Self::deposit_event(Event::SubmissionReceived(
+				feed_id,
+				round_id,
+				submission,
+				oracle.clone(),
+			));
+
+			// update round answer
+			let (min_count, max_count) = details.submission_count_bounds;
+			if details.submissions.len() >= min_count as usize {
+				let updated_at = frame_system::Pallet::<T>::block_number();
+				let new_answer = median(&mut details.submissions.to_vec());
+				T::AnswerValidator::validate(feed_id, round_id, new_answer)?;
+				let Round {
+					started_at,
+					// set if the round already reached the minimum before this submission,
+					// the alerts below are only emitted when their condition becomes true
+					answer: previous_answer,
+					..
+				} = Self::round(feed_id, round_id).ok_or(Error::<T>::RoundNotFound)?;
+				// the latest answer of a previous round
+				let last_answer = feed
+					.config
+					.first_valid_round
+					.filter(|first| *first < round_id)
+					.and_then(|_| {
+						let prev_round_id =
+							feed.config.latest_round.min(round_id.saturating_sub(1));
+						Self::round(feed_id, prev_round_id)
+					})
+					.and_then(|r| r.answer);
+				let threshold = feed.config.deviation_alert_percent;
+				if let Some(last_answer) = last_answer {
+					let exceeds = |answer| exceeds_deviation(last_answer, answer, threshold);
+					if threshold > 0
+						&& exceeds(new_answer)
+						&& !previous_answer.map_or(false, exceeds)
+					{
+						Self::deposit_event(Event::AnswerDeviationHigh(
+							feed_id,
+							round_id,
+							last_answer,
+							new_answer,
+							threshold,
+						));
+					}
+				}
+				let submission_count = details.submissions.len() as u32;
+				// the previous submissions were identical as well if they answered the round
+				let already_suspected = previous_answer.is_some() && submission_count > 2;
+				if submission_count > 1
+					&& !already_suspected
+					&& details.submissions.iter().all(|s| *s == submission)
+				{
+					Self::deposit_event(Event::OracleCollusionSuspected(
+						feed_id,
+						round_id,
+						submission,
+						submission_count,
+					));
+				}
+				let round = RoundData {
+					round_id,
+					started_at,
+					answer: new_answer,
+					updated_at,
+					answered_in_round: round_id,
+				};
+
+				Rounds::<T>::insert(feed_id, round_id, round.clone().into_round());
+
+				feed.config.latest_round = round_id;
+				if feed.config.first_valid_round.is_none() {
+					feed.config.first_valid_round = Some(round_id);
+				}
+				// the previous rounds is not eligible for answers any more, so we close it
+				let prev_round_id = round_id.saturating_sub(1);
+				if prev_round_id > 0 {
+					Details::<T>::remove(feed_id, prev_round_id);
+				}
+				// prune the oldest round
+				feed.prune_oldest();
+
+				T::OnAnswerHandler::on_answer(feed_id, round);
+				// allow subscribers to skip answers that do not change the value
+				if last_answer == Some(new_answer) {
+					// already reported for an earlier submission to this round
+					if previous_answer != Some(new_answer) {
+						Self::deposit_event(Event::AnswerUnchanged(
+							feed_id, round_id, new_answer, updated_at,
+						));
+					}
+				} else {
+					Self::deposit_event(Event::AnswerUpdated(
+						feed_id, round_id, new_answer, updated_at,
+					));
+				}
+			}
+
+			// pay the treasury share right away
+			if !treasury_share.is_zero() {
+				T::Currency::transfer(
+					&Self::fund_account_id(),
+					&T::TreasuryAccount::get(),
+					treasury_share,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+
+			// update oracle rewards and try to reserve them
+			// track the debt in case we cannot reserve
+			T::Currency::reserve(&Self::fund_account_id(), payment).or_else(
+				|_| -> DispatchResult {
+					// track the debt in case we cannot reserve
+					let mut new_debt = feed.config.debt;
+					new_debt = new_debt.checked_add(&payment).ok_or(Error::<T>::Overflow)?;
+
+					if let Some(max_debt) = feed.config.max_debt {
+						ensure!(new_debt <= max_debt, <Error<T>>::MaxDebtReached);
+					}
+
+					feed.config.debt = new_debt;
+					Ok(())
+				},
+			)?;
+
+			let mut oracle_meta = Self::oracle(&oracle).ok_or(Error::<T>::OracleNotFound)?;
+			oracle_meta.withdrawable = oracle_meta
+				.withdrawable
+				.checked_add(&payment)
+				.ok_or(Error::<T>::Overflow)?;
+			oracle_meta.total_earned = oracle_meta.total_earned.saturating_add(payment);
+			Oracles::<T>::insert(&oracle, oracle_meta);
+
+			// delete the details if the maximum count has been reached
+			if details.submissions.len() < max_count as usize {
+				Details::<T>::insert(feed_id, round_id, details);
+			}
+
+			Ok(().into())
+		}
+
+		/// Upper bound of the weight of removing the oracle statuses of an empty feed in
+		/// `change_oracles`: checking for open rounds and removing up to `OracleCountLimit`
+		/// statuses.
+		fn remove_oracle_statuses_weight() -> Weight {
+			let limit = T::OracleCountLimit::get() as Weight;
+			T::DbWeight::get().reads_writes(limit.saturating_add(2), limit)
+		}
+
+		/// Replace the owner of the feed on behalf of the pallet admin `sender`.
+		///
+		/// Only compiled with the `admin-override` feature (and for tests and benchmarks).
+		#[cfg(any(test, feature = "admin-override", feature = "runtime-benchmarks"))]
+		fn do_override_feed_owner(
+			sender: T::AccountId,
+			feed_id: T::FeedId,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure!(sender == Self::pallet_admin(), Error::<T>::NotPalletAdmin);
+			let mut feed = Self::feed_config(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+
+			let old_owner = sp_std::mem::replace(&mut feed.owner, new_owner.clone());
+			feed.pending_owner = None;
+			Feeds::<T>::insert(feed_id, feed);
+
+			Self::deposit_event(Event::OwnerOverridden(feed_id, old_owner, new_owner));
+
+			Ok(().into())
+		}
+
+		/// Without the `admin-override` feature the override is always rejected.
+		#[cfg(not(any(test, feature = "admin-override", feature = "runtime-benchmarks")))]
+		fn do_override_feed_owner(
+			_sender: T::AccountId,
+			_feed_id: T::FeedId,
+			_new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Err(Error::<T>::OwnerOverrideDisabled.into())
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		// --- feed operations ---
+
+		/// Create a new oracle feed with the given config values.
+		/// Limited to feed creator accounts.
+		#[pallet::weight(T::WeightInfo::create_feed(oracles.len() as u32, tags.len() as u32))]
+		#[allow(clippy::too_many_arguments)]
+		#[transactional]
+		pub fn create_feed(
+			origin: OriginFor<T>,
+			payment: BalanceOf<T>,
+			timeout: T::BlockNumber,
+			submission_value_bounds: (T::Value, T::Value),
+			min_submissions: u32,
+			decimals: u8,
+			description: Vec<u8>,
+			restart_delay: RoundId,
+			oracles: Vec<(T::AccountId, T::AccountId)>,
+			pruning_window: Option<RoundId>,
+			max_debt: Option<BalanceOf<T>>,
+			min_oracle_count: u32,
+			tags: Vec<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			ensure!(
+				FeedCreators::<T>::contains_key(&owner),
+				Error::<T>::NotFeedCreator
+			);
+			let description: BoundedVec<u8, T::StringLimit> = description
+				.try_into()
+				.map_err(|_| Error::<T>::DescriptionTooLong)?;
+			let tags = Self::bounded_tags(tags)?;
+			// a minimum of 0 would close every round with the first submission
+			ensure!(min_submissions > 0, Error::<T>::ZeroMinSubmissions);
+
+			let pruning_window = pruning_window.unwrap_or(RoundId::MAX);
+			let submission_count_bounds = (min_submissions, oracles.len() as u32);
+
+			let id: T::FeedId = FeedCounter::<T>::get();
+			ensure!(id < T::FeedLimit::get(), Error::<T>::FeedLimitReached);
+
+			let new_config = FeedConfig {
+				id,
+				owner: owner.clone(),
+				pending_owner: None,
+				payment,
+				timeout,
+				submission_value_bounds,
+				submission_count_bounds,
+				decimals,
+				description,
+				restart_delay,
+				latest_round: Zero::zero(),
+				reporting_round: Zero::zero(),
+				first_valid_round: None,
+				oracle_count: Zero::zero(),
+				pruning_window,
+				next_round_to_prune: RoundId::one(),
+				debt: Zero::zero(),
+				max_debt,
+				deviation_alert_percent: Zero::zero(),
+				treasury_percent: Zero::zero(),
+				max_rounds_open: Zero::zero(),
+				created_at: frame_system::Pallet::<T>::block_number(),
+				min_oracle_count,
+				tags,
+				submission_deadline_offset: None,
+				deprecated: false,
+				version: crate::CURRENT_VERSION,
+			};
+			validate_feed_config::<T>(&new_config, oracles.len() as u32)?;
+
+			let new_id = id.checked_add(&One::one()).ok_or(Error::<T>::Overflow)?;
+			FeedCounter::<T>::put(new_id);
+			Self::index_description(id, &new_config.description);
+			for tag in new_config.tags.iter() {
+				FeedsByTag::<T>::insert(tag, id, ());
+			}
+			UninitializedFeeds::<T>::append(id);
+			let mut feed = Feed::<T>::new(id, new_config); // synced on drop
+			let started_at = frame_system::Pallet::<T>::block_number();
+			let updated_at = Some(started_at);
+			// Store a dummy value for round 0 because we will not get useful data for
+			// it, but need some seed data that future rounds can carry over.
+			Rounds::<T>::insert(
+				id,
+				RoundId::zero(),
+				Round {
+					started_at,
+					answer: Some(Zero::zero()),
+					updated_at,
+					answered_in_round: Some(Zero::zero()),
+				},
+			);
+			feed.add_oracles(oracles)?;
+			// validate the rounds config
+			feed.update_future_rounds(payment, submission_count_bounds, restart_delay, timeout)?;
+			Self::deposit_event(Event::FeedCreated(id, owner));
+			Ok(().into())
+		}
+
+		/// Initiate the transfer of the feed to `new_owner`.
+		#[pallet::weight(T::WeightInfo::transfer_ownership())]
+		pub fn transfer_ownership(
+			origin: OriginFor<T>,
 			feed_id: T::FeedId,
 			new_owner: T::AccountId,
 		) -> DispatchResultWithPostInfo {
@@ -727,6 +2134,127 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Prune a single round of an existing feed.
+		/// Limited to the owner of a feed.
+		///
+		/// - Fails with `NothingToPrune` if the round is within the pruning window.
+		#[pallet::weight(T::WeightInfo::prune_single_round())]
+		pub fn prune_single_round(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			round_id: RoundId,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			ensure!(round_id > RoundId::zero(), Error::<T>::CannotPruneRoundZero);
+
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
+			ensure!(feed.prune_round(round_id), Error::<T>::NothingToPrune);
+
+			Ok(().into())
+		}
+
+		/// Set the deviation (in percent) between consecutive answers above which
+		/// an `AnswerDeviationHigh` event is emitted. `0` disables the alert.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(T::WeightInfo::set_deviation_alert())]
+		pub fn set_deviation_alert(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			percent: u8,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
+
+			feed.config.deviation_alert_percent = percent;
+
+			Ok(().into())
+		}
+
+		/// Set the number of oracles the feed cannot drop below. `0` disables the limit.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(T::WeightInfo::set_min_oracle_count())]
+		pub fn set_min_oracle_count(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			min_oracle_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
+
+			let mut config = feed.config.clone();
+			config.min_oracle_count = min_oracle_count;
+			validate_feed_config::<T>(&config, feed.oracle_count())?;
+			feed.config = config;
+			Self::deposit_event(Event::MinOracleCountSet(feed_id, min_oracle_count));
+
+			Ok(().into())
+		}
+
+		/// Replace the tags of the feed.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(T::WeightInfo::set_tags(tags.len() as u32))]
+		pub fn set_tags(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			tags: Vec<Vec<u8>>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
+
+			let tags = Self::bounded_tags(tags)?;
+			for tag in feed.config.tags.iter() {
+				FeedsByTag::<T>::remove(tag, feed_id);
+			}
+			for tag in tags.iter() {
+				FeedsByTag::<T>::insert(tag, feed_id, ());
+			}
+			feed.config.tags = tags;
+			Self::deposit_event(Event::TagsSet(feed_id));
+
+			Ok(().into())
+		}
+
+		/// Mark the feed as end-of-life so consumers can migrate to another feed.
+		/// Deprecated feeds reject all submissions.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(T::WeightInfo::deprecate_feed())]
+		pub fn deprecate_feed(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
+
+			feed.config.deprecated = true;
+			Self::deposit_event(Event::FeedDeprecated(feed_id, owner));
+
+			Ok(().into())
+		}
+
+		/// Accept submissions for a deprecated feed again.
+		/// Limited to the owner of a feed and the pallet admin.
+		#[pallet::weight(T::WeightInfo::undeprecate_feed())]
+		pub fn undeprecate_feed(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			if sender != Self::pallet_admin() {
+				feed.ensure_owner(&sender)?;
+			}
+
+			feed.config.deprecated = false;
+			Self::deposit_event(Event::FeedUndeprecated(feed_id, sender));
+
+			Ok(().into())
+		}
+
 		/// Submit a new value to the given feed and round.
 		///
 		/// - Will start a new round if there is no round for the id, yet,
@@ -747,173 +2275,334 @@ pub mod pallet {
 			#[pallet::compact] submission: T::Value,
 		) -> DispatchResultWithPostInfo {
 			let oracle = ensure_signed(origin)?;
+			Self::do_submit(oracle, feed_id, round_id, submission)
+		}
 
-			with_transaction_result(|| -> DispatchResultWithPostInfo {
-				let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
-				let mut oracle_status =
-					Self::oracle_status(feed_id, &oracle).ok_or(Error::<T>::NotOracle)?;
-				feed.ensure_valid_round(&oracle, round_id)?;
-
-				let (min_val, max_val) = feed.config.submission_value_bounds;
-				ensure!(submission >= min_val, Error::<T>::SubmissionBelowMinimum);
-				ensure!(submission <= max_val, Error::<T>::SubmissionAboveMaximum);
-
-				let new_round_id = feed.reporting_round_id().saturating_add(One::one());
-				let next_eligible_round = oracle_status
-					.last_started_round
-					.unwrap_or_else(Zero::zero)
-					.checked_add(feed.config.restart_delay)
-					.ok_or(Error::<T>::Overflow)?
-					.checked_add(One::one())
-					.ok_or(Error::<T>::Overflow)?;
-				let eligible_to_start =
-					round_id >= next_eligible_round || oracle_status.last_started_round.is_none();
+		/// Submit a new value to the given feed and round without paying transaction fees.
+		///
+		/// The `signature` has to be a signature of the oracle account (given by `public`)
+		/// over the SCALE encoded `(feed_id, round_id, submission)` payload.
+		/// Otherwise behaves like `submit`.
+		///
+		/// Limited to the oracles of a feed.
+		#[pallet::weight(T::WeightInfo::submit_opening_round_answers().max(
+		T::WeightInfo::submit_closing_answer(T::OracleCountLimit::get())
+		))]
+		pub fn submit_unsigned(
+			origin: OriginFor<T>,
+			#[pallet::compact] feed_id: T::FeedId,
+			#[pallet::compact] round_id: RoundId,
+			#[pallet::compact] submission: T::Value,
+			// the signature is checked in `validate_unsigned`
+			_signature: T::Signature,
+			public: T::Public,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			Self::do_submit(public.into_account(), feed_id, round_id, submission)
+		}
 
-				// initialize the round if conditions are met
-				if round_id == new_round_id && eligible_to_start {
-					let started_at = feed.initialize_round(new_round_id)?;
+		/// Disable and add oracles for the given feed.
+		/// If no oracle is left afterwards and the feed has no open rounds, up to
+		/// `OracleCountLimit` oracle statuses of the feed are removed. Repeat the call
+		/// without oracles to remove the rest.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(
+			T::WeightInfo::change_oracles(to_disable.len() as u32, to_add.len() as u32)
+				.saturating_add(Pallet::<T>::remove_oracle_statuses_weight())
+		)]
+		#[transactional]
+		pub fn change_oracles(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			to_disable: Vec<T::AccountId>,
+			to_add: Vec<(T::AccountId, T::AccountId)>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let weight =
+				T::WeightInfo::change_oracles(to_disable.len() as u32, to_add.len() as u32);
 
-					Self::deposit_event(Event::NewRound(
-						feed_id,
-						new_round_id,
-						oracle.clone(),
-						started_at,
-					));
+			// synced on drop
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
+			feed.disable_oracles(to_disable)?;
+			feed.add_oracles(to_add)?;
+			ensure!(
+				feed.oracle_count() >= feed.config.min_oracle_count,
+				Error::<T>::BelowMinimumOracleCount
+			);
+			// Only clean up if the feed was emptied, so the common case does not
+			// pay for iterating the statuses. The statuses of open rounds are kept as
+			// they record the last reported round and prevent double submissions.
+			if !feed.oracle_count().is_zero() || Details::<T>::iter_prefix(feed_id).next().is_some()
+			{
+				return Ok(Some(weight).into());
+			}
+			let removed = OracleStatuses::<T>::drain_prefix(feed_id)
+				.take(T::OracleCountLimit::get() as usize)
+				.count();
+			if removed > 0 && OracleStatuses::<T>::iter_prefix(feed_id).next().is_none() {
+				Self::deposit_event(Event::AllOraclesRemoved(feed_id));
+			}
 
-					oracle_status.last_started_round = Some(new_round_id);
-				}
+			Ok(Some(weight.saturating_add(Self::remove_oracle_statuses_weight())).into())
+		}
 
-				// record submission
-				let mut details = Details::<T>::take(feed_id, round_id)
-					.ok_or(Error::<T>::NotAcceptingSubmissions)?;
-				details.submissions.push(submission);
+		/// Update the configuration for future oracle rounds.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(T::WeightInfo::update_future_rounds())]
+		#[transactional]
+		pub fn update_future_rounds(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			payment: BalanceOf<T>,
+			submission_count_bounds: (u32, u32),
+			restart_delay: RoundId,
+			timeout: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			// synced on drop
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
 
-				oracle_status.last_reported_round = Some(round_id);
-				oracle_status.latest_submission = Some(submission);
-				OracleStatuses::<T>::insert(feed_id, &oracle, oracle_status);
-				Self::deposit_event(Event::SubmissionReceived(
-					feed_id,
-					round_id,
-					submission,
-					oracle.clone(),
-				));
+			feed.update_future_rounds(payment, submission_count_bounds, restart_delay, timeout)?;
 
-				// update round answer
-				let (min_count, max_count) = details.submission_count_bounds;
-				if details.submissions.len() >= min_count as usize {
-					let updated_at = frame_system::Pallet::<T>::block_number();
-					let new_answer = median(&mut details.submissions);
-					let round = RoundData {
-						started_at: Self::round(feed_id, round_id)
-							.ok_or(Error::<T>::RoundNotFound)?
-							.started_at,
-						answer: new_answer,
-						updated_at,
-						answered_in_round: round_id,
-					};
+			Ok(().into())
+		}
 
-					Rounds::<T>::insert(feed_id, round_id, round.clone().into_round());
+		/// Update the oracle payment of future rounds, keeping the other round parameters.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(T::WeightInfo::set_payment_amount())]
+		pub fn set_payment_amount(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			payment_amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			// synced on drop
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
 
-					feed.config.latest_round = round_id;
-					if feed.config.first_valid_round.is_none() {
-						feed.config.first_valid_round = Some(round_id);
-					}
-					// the previous rounds is not eligible for answers any more, so we close it
-					let prev_round_id = round_id.saturating_sub(1);
-					if prev_round_id > 0 {
-						Details::<T>::remove(feed_id, prev_round_id);
-					}
-					// prune the oldest round
-					feed.prune_oldest();
+			feed.update_future_rounds(
+				payment_amount,
+				feed.config.submission_count_bounds,
+				feed.config.restart_delay,
+				feed.config.timeout,
+			)?;
+			Self::deposit_event(Event::PaymentAmountUpdated(feed_id, payment_amount));
 
-					T::OnAnswerHandler::on_answer(feed_id, round);
-					Self::deposit_event(Event::AnswerUpdated(
-						feed_id, round_id, new_answer, updated_at,
-					));
-				}
+			Ok(().into())
+		}
+
+		/// Update the timeout of future rounds, keeping the other round parameters.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(T::WeightInfo::set_timeout())]
+		pub fn set_timeout(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			timeout: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			// synced on drop
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
+
+			feed.update_future_rounds(
+				feed.config.payment,
+				feed.config.submission_count_bounds,
+				feed.config.restart_delay,
+				timeout,
+			)?;
+			Self::deposit_event(Event::TimeoutUpdated(feed_id, timeout));
+
+			Ok(().into())
+		}
+
+		/// Atomically update multiple parameters of the feed config.
+		/// Only the fields set in `updates` are changed.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(T::WeightInfo::update_feed_config())]
+		#[transactional]
+		pub fn update_feed_config(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			updates: FeedConfigUpdatesOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			// synced on drop
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
+
+			let FeedConfigUpdates {
+				payment,
+				timeout,
+				submission_value_bounds,
+				submission_count_bounds,
+				restart_delay,
+				description,
+				deviation_alert_percent,
+				treasury_percent,
+				max_rounds_open,
+				submission_deadline_offset,
+			} = updates;
+
+			if payment.is_some()
+				|| timeout.is_some()
+				|| submission_count_bounds.is_some()
+				|| restart_delay.is_some()
+			{
+				feed.update_future_rounds(
+					payment.unwrap_or(feed.config.payment),
+					submission_count_bounds.unwrap_or(feed.config.submission_count_bounds),
+					restart_delay.unwrap_or(feed.config.restart_delay),
+					timeout.unwrap_or(feed.config.timeout),
+				)?;
+			}
+			if let Some((min, max)) = submission_value_bounds {
+				ensure!(
+					min <= max && max <= T::MaxSubmissionValue::get(),
+					Error::<T>::WrongBounds
+				);
+				feed.config.submission_value_bounds = (min, max);
+			}
+			if let Some(description) = description {
+				let description: BoundedVec<u8, T::StringLimit> = description
+					.try_into()
+					.map_err(|_| Error::<T>::DescriptionTooLong)?;
+				FeedsByDescription::<T>::mutate_exists(
+					T::Hashing::hash_of(&feed.config.description),
+					|indexed| {
+						if *indexed == Some(feed_id) {
+							*indexed = None;
+						}
+					},
+				);
+				Self::index_description(feed_id, &description);
+				feed.config.description = description;
+			}
+			if let Some(percent) = deviation_alert_percent {
+				feed.config.deviation_alert_percent = percent;
+			}
+			if let Some(percent) = treasury_percent {
+				ensure!(percent <= 100, Error::<T>::TreasuryPercentTooHigh);
+				feed.config.treasury_percent = percent;
+			}
+			if let Some(max_rounds_open) = max_rounds_open {
+				feed.config.max_rounds_open = max_rounds_open;
+			}
+			if let Some(offset) = submission_deadline_offset {
+				feed.config.submission_deadline_offset = offset;
+			}
 
-				// update oracle rewards and try to reserve them
-				let payment = details.payment;
-				// track the debt in case we cannot reserve
-				T::Currency::reserve(&Self::account_id(), payment).or_else(
-					|_| -> DispatchResult {
-						// track the debt in case we cannot reserve
-						let mut new_debt = feed.config.debt;
-						new_debt = new_debt.checked_add(&payment).ok_or(Error::<T>::Overflow)?;
-
-						if let Some(max_debt) = feed.config.max_debt {
-							ensure!(new_debt <= max_debt, <Error<T>>::MaxDebtReached);
-						}
+			Ok(().into())
+		}
 
-						feed.config.debt = new_debt;
-						Ok(())
-					},
-				)?;
+		/// Override the oracle payment of a single round.
+		/// The round must not have received any submissions, yet.
+		/// Rounds that have not been started, yet, use the payment once they are initialized.
+		/// Limited to the owner of a feed.
+		#[pallet::weight(T::WeightInfo::set_round_payment())]
+		pub fn set_round_payment(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			round_id: RoundId,
+			payment: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			let feed = Feed::<T>::read_only_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
 
-				let mut oracle_meta = Self::oracle(&oracle).ok_or(Error::<T>::OracleNotFound)?;
-				oracle_meta.withdrawable = oracle_meta
-					.withdrawable
-					.checked_add(&payment)
-					.ok_or(Error::<T>::Overflow)?;
-				Oracles::<T>::insert(&oracle, oracle_meta);
+			if let Some(mut details) = feed.details(round_id) {
+				ensure!(
+					details.submissions.is_empty(),
+					Error::<T>::RoundHasSubmissions
+				);
+				details.payment = payment;
+				Details::<T>::insert(feed_id, round_id, details);
+			} else {
+				ensure!(
+					round_id > feed.reporting_round_id(),
+					Error::<T>::InvalidRound
+				);
+				RoundPaymentOverrides::<T>::insert(feed_id, round_id, payment);
+			}
 
-				// delete the details if the maximum count has been reached
-				if details.submissions.len() < max_count as usize {
-					Details::<T>::insert(feed_id, round_id, details);
-				}
+			Self::deposit_event(Event::RoundPaymentSet(feed_id, round_id, payment));
 
-				Ok(().into())
-			})
+			Ok(().into())
 		}
 
-		/// Disable and add oracles for the given feed.
+		/// Close a round that has reached the minimum number of submissions before the
+		/// maximum is reached, e.g. to reduce latency.
 		/// Limited to the owner of a feed.
-		#[pallet::weight(T::WeightInfo::change_oracles(to_disable.len() as u32, to_add.len() as u32))]
-		pub fn change_oracles(
+		///
+		/// The answer recorded once the minimum was reached is kept and later
+		/// submissions for the round are rejected.
+		#[pallet::weight(T::WeightInfo::close_round())]
+		#[transactional]
+		pub fn close_round(
 			origin: OriginFor<T>,
 			feed_id: T::FeedId,
-			to_disable: Vec<T::AccountId>,
-			to_add: Vec<(T::AccountId, T::AccountId)>,
+			round_id: RoundId,
 		) -> DispatchResultWithPostInfo {
 			let owner = ensure_signed(origin)?;
+			let feed = Feed::<T>::read_only_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			feed.ensure_owner(&owner)?;
 
-			with_transaction_result(|| -> DispatchResultWithPostInfo {
-				// synced on drop
-				let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
-				feed.ensure_owner(&owner)?;
-				feed.disable_oracles(to_disable)?;
-				feed.add_oracles(to_add)?;
+			ensure!(feed.round(round_id).is_some(), Error::<T>::RoundNotFound);
+			let details = match feed.details(round_id) {
+				Some(details) => details,
+				None if feed.was_updated(round_id) => {
+					return Err(Error::<T>::RoundAlreadyAnswered.into())
+				}
+				None => return Err(Error::<T>::NotAcceptingSubmissions.into()),
+			};
+			let (min_count, _) = details.submission_count_bounds;
+			// a round without submissions has no answer, even for a minimum of zero
+			ensure!(
+				!details.submissions.is_empty() && details.submissions.len() >= min_count as usize,
+				Error::<T>::NotEnoughSubmissions
+			);
 
-				Ok(().into())
-			})
+			Details::<T>::remove(feed_id, round_id);
+			Self::deposit_event(Event::RoundClosed(feed_id, round_id));
+
+			Ok(().into())
 		}
 
-		/// Update the configuration for future oracle rounds.
-		/// Limited to the owner of a feed.
-		#[pallet::weight(T::WeightInfo::update_future_rounds())]
-		pub fn update_future_rounds(
+		/// Close a round that timed out without being answered and remove its details,
+		/// e.g. if no new round is started that would close it.
+		/// Callable by anyone.
+		///
+		/// Emits `RoundTimedOut` (or `RoundAborted` if the round received submissions).
+		/// The caller receives `TimeoutCleanupReward` from the fund account unless
+		/// this would bring the fund below the minimum reserve.
+		#[pallet::weight(T::WeightInfo::close_timed_out_round())]
+		#[transactional]
+		pub fn close_timed_out_round(
 			origin: OriginFor<T>,
 			feed_id: T::FeedId,
-			payment: BalanceOf<T>,
-			submission_count_bounds: (u32, u32),
-			restart_delay: RoundId,
-			timeout: T::BlockNumber,
+			round_id: RoundId,
 		) -> DispatchResultWithPostInfo {
-			let owner = ensure_signed(origin)?;
-			with_transaction_result(|| {
-				// synced on drop
-				let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
-				feed.ensure_owner(&owner)?;
+			let caller = ensure_signed(origin)?;
+			let feed = Feed::<T>::read_only_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			ensure!(
+				!feed.was_updated(round_id),
+				Error::<T>::RoundAlreadyAnswered
+			);
+			ensure!(feed.is_timed_out(round_id), Error::<T>::RoundNotTimedOut);
 
-				feed.update_future_rounds(
-					payment,
-					submission_count_bounds,
-					restart_delay,
-					timeout,
-				)?;
+			feed.close_timed_out_round(round_id)?;
 
-				Ok(().into())
-			})
+			let reward = T::TimeoutCleanupReward::get();
+			let fund = Self::fund_account_id();
+			let can_pay = T::Currency::free_balance(&fund)
+				.checked_sub(&reward)
+				.map_or(false, |rest| rest >= T::MinimumReserve::get());
+			if !reward.is_zero() && can_pay {
+				T::Currency::transfer(&fund, &caller, reward, ExistenceRequirement::KeepAlive)?;
+			}
+
+			Ok(().into())
 		}
 
 		// --- feed: round requests ---
@@ -930,6 +2619,10 @@ pub mod pallet {
 			let owner = ensure_signed(origin)?;
 			let feed = Self::feed_config(feed_id).ok_or(Error::<T>::FeedNotFound)?;
 			ensure!(feed.owner == owner, Error::<T>::NotFeedOwner);
+			ensure!(
+				delay >= T::MinRequesterDelay::get(),
+				Error::<T>::RequesterDelayTooSmall
+			);
 
 			// Keep the `last_started_round` if the requester already existed.
 			let mut requester_meta = Self::requester(feed_id, &requester).unwrap_or_default();
@@ -971,6 +2664,7 @@ pub mod pallet {
 		/// Request the start of a new oracle round.
 		/// Limited to accounts with "requester" permission.
 		#[pallet::weight(T::WeightInfo::request_new_round())]
+		#[transactional]
 		pub fn request_new_round(
 			origin: OriginFor<T>,
 			feed_id: T::FeedId,
@@ -978,30 +2672,29 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 			let mut requester =
 				Self::requester(feed_id, &sender).ok_or(Error::<T>::NotAuthorizedRequester)?;
+			// the minimum might have been raised after the requester was set
+			ensure!(
+				requester.delay >= T::MinRequesterDelay::get(),
+				Error::<T>::RequesterDelayTooSmall
+			);
 
-			with_transaction_result(|| -> DispatchResultWithPostInfo {
-				let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
 
-				let new_round = feed
-					.reporting_round_id()
-					.checked_add(One::one())
-					.ok_or(Error::<T>::Overflow)?;
-				let last_started = requester.last_started_round.unwrap_or_else(Zero::zero);
-				let next_allowed_round = last_started
-					.checked_add(requester.delay)
-					.ok_or(Error::<T>::Overflow)?;
-				ensure!(
-					requester.last_started_round.is_none() || new_round > next_allowed_round,
-					Error::<T>::CannotRequestRoundYet
-				);
+			let new_round = feed
+				.reporting_round_id()
+				.checked_add(One::one())
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(
+				Self::may_start_round(requester.last_started_round, requester.delay, new_round)?,
+				Error::<T>::CannotRequestRoundYet
+			);
 
-				requester.last_started_round = Some(new_round);
-				Requesters::<T>::insert(feed_id, &sender, requester);
+			requester.last_started_round = Some(new_round);
+			Requesters::<T>::insert(feed_id, &sender, requester);
 
-				feed.request_new_round(sender)?;
+			feed.request_new_round(sender)?;
 
-				Ok(().into())
-			})
+			Ok(().into())
 		}
 
 		// --- oracle operations ---
@@ -1023,8 +2716,9 @@ pub mod pallet {
 				.withdrawable
 				.checked_sub(&amount)
 				.ok_or(Error::<T>::InsufficientFunds)?;
+			oracle_meta.total_withdrawn = oracle_meta.total_withdrawn.saturating_add(amount);
 
-			let fund = T::PalletId::get().into_account();
+			let fund = Self::fund_account_id();
 			ensure!(
 				T::Currency::reserved_balance(&fund) >= amount,
 				Error::<T>::InsufficientReserve
@@ -1037,6 +2731,44 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Withdraw the whole payment of all given oracles to `recipient` in a single transfer.
+		/// Limited to the oracle admin, fails if any of the oracles is administered by another account.
+		#[pallet::weight(T::WeightInfo::withdraw_payment_all(oracles.len() as u32))]
+		#[transactional]
+		pub fn withdraw_payment_all(
+			origin: OriginFor<T>,
+			oracles: Vec<T::AccountId>,
+			recipient: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let admin = ensure_signed(origin)?;
+
+			let mut amount: BalanceOf<T> = Zero::zero();
+			for oracle in oracles {
+				let mut oracle_meta = Self::oracle(&oracle).ok_or(Error::<T>::OracleNotFound)?;
+				ensure!(oracle_meta.admin == admin, Error::<T>::NotAdmin);
+				amount = amount
+					.checked_add(&oracle_meta.withdrawable)
+					.ok_or(Error::<T>::Overflow)?;
+				// written immediately so duplicate oracles are only paid once
+				oracle_meta.total_withdrawn = oracle_meta
+					.total_withdrawn
+					.saturating_add(oracle_meta.withdrawable);
+				oracle_meta.withdrawable = Zero::zero();
+				Oracles::<T>::insert(&oracle, oracle_meta);
+			}
+
+			let fund = Self::fund_account_id();
+			ensure!(
+				T::Currency::reserved_balance(&fund) >= amount,
+				Error::<T>::InsufficientReserve
+			);
+			T::Currency::unreserve(&fund, amount);
+
+			T::Currency::transfer(&fund, &recipient, amount, ExistenceRequirement::KeepAlive)?;
+
+			Ok(().into())
+		}
+
 		/// Initiate an admin transfer for the given oracle.
 		/// Limited to the oracle admin account.
 		#[pallet::weight(T::WeightInfo::transfer_admin())]
@@ -1060,6 +2792,34 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Initiate admin transfers for all given `(oracle, new_admin)` pairs.
+		/// Limited to the oracle admin account, fails if any of the oracles is
+		/// administered by another account.
+		#[pallet::weight(T::WeightInfo::transfer_admin_batch(transfers.len() as u32))]
+		#[transactional]
+		pub fn transfer_admin_batch(
+			origin: OriginFor<T>,
+			transfers: Vec<(T::AccountId, T::AccountId)>,
+		) -> DispatchResultWithPostInfo {
+			let old_admin = ensure_signed(origin)?;
+
+			for (oracle, new_admin) in transfers {
+				let mut oracle_meta = Self::oracle(&oracle).ok_or(Error::<T>::OracleNotFound)?;
+				ensure!(oracle_meta.admin == old_admin, Error::<T>::NotAdmin);
+
+				oracle_meta.pending_admin = Some(new_admin.clone());
+				Oracles::<T>::insert(&oracle, oracle_meta);
+
+				Self::deposit_event(Event::OracleAdminUpdateRequested(
+					oracle,
+					old_admin.clone(),
+					new_admin,
+				));
+			}
+
+			Ok(().into())
+		}
+
 		/// Complete an admin transfer for the given oracle.
 		/// Limited to the pending oracle admin account.
 		#[pallet::weight(T::WeightInfo::accept_admin())]
@@ -1099,7 +2859,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			ensure!(sender == Self::pallet_admin(), Error::<T>::NotPalletAdmin);
-			let fund = T::PalletId::get().into_account();
+			let fund = Self::fund_account_id();
 			let reserve = T::Currency::free_balance(&fund);
 			let new_reserve = reserve
 				.checked_sub(&amount)
@@ -1126,13 +2886,111 @@ pub mod pallet {
 			let mut feed = <Feed<T>>::load_from(feed_id).ok_or(<Error<T>>::FeedNotFound)?;
 
 			let to_reserve = amount.min(feed.config.debt);
-			T::Currency::reserve(&Self::account_id(), to_reserve)?;
+			T::Currency::reserve(&Self::fund_account_id(), to_reserve)?;
 			// it's fine if we saturate to 0 debt
 			feed.config.debt = feed.config.debt.saturating_sub(amount);
 
 			Ok(().into())
 		}
 
+		/// Prune the rounds outside the pruning window of every feed.
+		/// Limited to the pallet admin.
+		///
+		/// Emits a `PruningCompleted` event for every feed that was pruned.
+		///
+		/// `feed_count` and `round_count` are witnesses for the number of feeds and the
+		/// number of rounds outside the pruning windows. They have to be at least the
+		/// actual counts, the unused weight is refunded.
+		#[pallet::weight(T::WeightInfo::prune_all_expired(*feed_count, *round_count))]
+		pub fn prune_all_expired(
+			origin: OriginFor<T>,
+			feed_count: u32,
+			round_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(sender == Self::pallet_admin(), Error::<T>::NotPalletAdmin);
+
+			let actual_feed_count: u32 = FeedCounter::<T>::get().unique_saturated_into();
+			ensure!(actual_feed_count <= feed_count, Error::<T>::InvalidWitness);
+			let (expired, expired_rounds) = Self::expired_feeds();
+			ensure!(expired_rounds <= round_count, Error::<T>::InvalidWitness);
+			let mut total_pruned: u32 = 0;
+			for feed_id in expired {
+				let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+				let mut pruned: RoundId = Zero::zero();
+				while feed.prune_oldest() {
+					pruned += RoundId::one();
+				}
+				total_pruned = total_pruned.saturating_add(pruned);
+				Self::deposit_event(Event::PruningCompleted(feed_id, pruned));
+			}
+
+			Ok(Some(T::WeightInfo::prune_all_expired(
+				actual_feed_count,
+				total_pruned,
+			))
+			.into())
+		}
+
+		/// Set the answer of an existing round, e.g. to unblock consumers if the oracles
+		/// fail to report. Limited to the pallet admin.
+		///
+		/// The round is closed, so oracles cannot submit to it afterwards. The submission
+		/// value bounds of the feed are not enforced.
+		#[pallet::weight(T::WeightInfo::force_answer())]
+		#[transactional]
+		pub fn force_answer(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			round_id: RoundId,
+			answer: T::Value,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(sender == Self::pallet_admin(), Error::<T>::NotPalletAdmin);
+			ensure!(round_id > Zero::zero(), Error::<T>::InvalidRound);
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			let started_at = Self::round(feed_id, round_id)
+				.ok_or(Error::<T>::RoundNotFound)?
+				.started_at;
+
+			let updated_at = frame_system::Pallet::<T>::block_number();
+			let round = RoundData {
+				round_id,
+				started_at,
+				answer,
+				updated_at,
+				answered_in_round: round_id,
+			};
+			Rounds::<T>::insert(feed_id, round_id, round.clone().into_round());
+			Details::<T>::remove(feed_id, round_id);
+
+			feed.config.latest_round = feed.config.latest_round.max(round_id);
+			if feed.config.first_valid_round.is_none() {
+				feed.config.first_valid_round = Some(round_id);
+			}
+
+			T::OnAnswerHandler::on_answer(feed_id, round);
+			Self::deposit_event(Event::AnswerUpdated(feed_id, round_id, answer, updated_at));
+			Self::deposit_event(Event::ForcedAnswer(feed_id, round_id, answer, sender));
+
+			Ok(().into())
+		}
+
+		/// Replace the owner of the feed, e.g. to recover a feed whose owner keys were lost.
+		/// Limited to the pallet admin.
+		///
+		/// Clears a pending ownership transfer. Fails with `OwnerOverrideDisabled` unless
+		/// the pallet is built with the `admin-override` feature.
+		#[pallet::weight(T::WeightInfo::pallet_admin_override_feed_owner())]
+		pub fn pallet_admin_override_feed_owner(
+			origin: OriginFor<T>,
+			feed_id: T::FeedId,
+			new_owner: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::do_override_feed_owner(sender, feed_id, new_owner)
+		}
+
 		/// Initiate an admin transfer for the pallet.
 		/// Limited to the pallet admin account.
 		#[pallet::weight(T::WeightInfo::transfer_pallet_admin())]
@@ -1178,6 +3036,40 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Cancel a pending admin transfer for the pallet.
+		/// Limited to the pallet admin account.
+		#[pallet::weight(T::WeightInfo::cancel_pallet_admin_transfer())]
+		pub fn cancel_pallet_admin_transfer(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let admin = ensure_signed(origin)?;
+			ensure!(Self::pallet_admin() == admin, Error::<T>::NotPalletAdmin);
+
+			let pending =
+				PendingPalletAdmin::<T>::take().ok_or(Error::<T>::NoPendingPalletAdmin)?;
+
+			Self::deposit_event(Event::PalletAdminTransferCancelled(pending));
+
+			Ok(().into())
+		}
+
+		/// Set the pallet admin without the two-step transfer, e.g. in an emergency.
+		/// Limited to the governance origin.
+		///
+		/// Cancels any pending pallet admin transfer.
+		#[pallet::weight(T::WeightInfo::force_set_pallet_admin())]
+		pub fn force_set_pallet_admin(
+			origin: OriginFor<T>,
+			new_pallet_admin: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			PendingPalletAdmin::<T>::kill();
+			PalletAdmin::<T>::put(&new_pallet_admin);
+
+			Self::deposit_event(Event::PalletAdminUpdated(new_pallet_admin));
+
+			Ok(().into())
+		}
+
 		/// Allow the given account to create oracle feeds.
 		/// Limited to the pallet admin account.
 		#[pallet::weight(T::WeightInfo::set_feed_creator())]
@@ -1213,6 +3105,34 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		/// Only accept unsigned submissions signed by an oracle that may submit for the round.
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::submit_unsigned(feed_id, round_id, submission, signature, public) = call {
+				let oracle = public.clone().into_account();
+				let payload = (feed_id, round_id, submission).encode();
+				if !signature.verify(&payload[..], &oracle) {
+					return InvalidTransaction::BadProof.into();
+				}
+				let feed = Feed::<T>::read_only_from(*feed_id).ok_or(InvalidTransaction::Call)?;
+				feed.ensure_valid_round(&oracle, *round_id)
+					.map_err(|_| InvalidTransaction::Stale)?;
+
+				ValidTransaction::with_tag_prefix("ChainlinkFeed")
+					.priority(T::UnsignedPriority::get())
+					.and_provides((feed_id, round_id, oracle))
+					.longevity(5)
+					.propagate(true)
+					.build()
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+	}
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub pallet_admin: Option<T::AccountId>,
@@ -1262,23 +3182,85 @@ pub mod pallet {
 		}
 	}
 
+	/// Check the invariants of a feed config for a feed with `oracle_count` oracles.
+	///
+	/// Returns the first violated invariant as an error. Does not touch storage, so it can
+	/// also be used to check a config before submitting `create_feed`.
+	/// (The length of the description is already enforced by its type.)
+	pub fn validate_feed_config<T: Config>(
+		config: &FeedConfigOf<T>,
+		oracle_count: u32,
+	) -> DispatchResult {
+		ensure!(
+			config.pruning_window > RoundId::zero(),
+			Error::<T>::CannotPruneRoundZero
+		);
+		ensure!(
+			oracle_count <= T::OracleCountLimit::get(),
+			Error::<T>::OraclesLimitExceeded
+		);
+		let (min_value, max_value) = config.submission_value_bounds;
+		ensure!(
+			min_value <= max_value && max_value <= T::MaxSubmissionValue::get(),
+			Error::<T>::WrongBounds
+		);
+		ensure!(
+			oracle_count >= config.min_oracle_count,
+			Error::<T>::BelowMinimumOracleCount
+		);
+		let (min, max) = config.submission_count_bounds;
+		// a minimum of 0 would close every round with the first submission
+		ensure!(min > 0, Error::<T>::ZeroMinSubmissions);
+		ensure!(max >= min, Error::<T>::WrongBounds);
+		// Make sure that both the min and max of submissions is
+		// less or equal to the number of oracles.
+		ensure!(oracle_count >= max, Error::<T>::MaxExceededTotal);
+		// Make sure that at least one oracle can request a new
+		// round.
+		ensure!(
+			oracle_count > config.restart_delay,
+			Error::<T>::DelayNotBelowCount
+		);
+		// Rounds have to remain answerable with the minimum number of oracles.
+		if config.min_oracle_count > 0 {
+			ensure!(min <= config.min_oracle_count, Error::<T>::WrongBounds);
+		}
+		ensure!(
+			config.treasury_percent <= 100,
+			Error::<T>::TreasuryPercentTooHigh
+		);
+		Ok(())
+	}
+
 	/// Proxy used for interaction with a feed.
 	/// `should_sync` flag determines whether the `config` is put into
 	/// storage on `drop`.
 	pub struct Feed<T: Config> {
-		pub id: T::FeedId,
 		pub config: FeedConfigOf<T>,
 		pub should_sync: bool,
 	}
 
+	/// Type erased read-only feed handle.
+	///
+	/// Allows storing handles to feeds of different types in the same collection.
+	pub type BoxedFeed<T> = Box<
+		dyn FeedInterface<
+			T,
+			FeedId = <T as Config>::FeedId,
+			Value = <T as Config>::Value,
+			Balance = BalanceOf<T>,
+		>,
+	>;
+
 	impl<T: Config> Feed<T> {
 		// --- constructors ---
 
 		/// Create a new feed with the given id and config.
+		/// The `id` of the config is overwritten with the given id.
 		/// Will store the config when dropped.
-		pub fn new(id: T::FeedId, config: FeedConfigOf<T>) -> Self {
+		pub fn new(id: T::FeedId, mut config: FeedConfigOf<T>) -> Self {
+			config.id = id;
 			Self {
-				id,
 				config,
 				should_sync: true,
 			}
@@ -1290,7 +3272,6 @@ pub mod pallet {
 		pub fn read_only_from(id: T::FeedId) -> Option<Self> {
 			let config = Feeds::<T>::get(id)?;
 			Some(Self {
-				id,
 				config,
 				should_sync: false,
 			})
@@ -1301,12 +3282,18 @@ pub mod pallet {
 		pub fn load_from(id: T::FeedId) -> Option<Self> {
 			let config = Feeds::<T>::get(id)?;
 			Some(Self {
-				id,
 				config,
 				should_sync: true,
 			})
 		}
 
+		/// Erase the type of the feed.
+		///
+		/// The `should_sync` behaviour is preserved.
+		pub fn into_boxed(self) -> BoxedFeed<T> {
+			Box::new(self)
+		}
+
 		// --- getters ---
 
 		/// Return the round oracles are currently reporting data for.
@@ -1316,17 +3303,17 @@ pub mod pallet {
 
 		/// Return the round data (including the answer, if present).
 		fn round(&self, round: RoundId) -> Option<RoundOf<T>> {
-			Rounds::<T>::get(self.id, round)
+			Rounds::<T>::get(self.config.id, round)
 		}
 
 		/// Return the round details (including submissions).
 		pub fn details(&self, round: RoundId) -> Option<RoundDetailsOf<T>> {
-			Details::<T>::get(self.id, round)
+			Details::<T>::get(self.config.id, round)
 		}
 
 		/// Return the oracle status associated with this feed.
 		fn status(&self, oracle: &T::AccountId) -> Option<OracleStatusOf<T>> {
-			OracleStatuses::<T>::get(self.id, oracle)
+			OracleStatuses::<T>::get(self.config.id, oracle)
 		}
 
 		/// Return the number of oracles that can submit data for this feed.
@@ -1376,7 +3363,7 @@ pub mod pallet {
 
 		/// Check whether a round is timed out.
 		/// Returns `false` for rounds not present in storage.
-		fn is_timed_out(&self, round: RoundId) -> bool {
+		pub(crate) fn is_timed_out(&self, round: RoundId) -> bool {
 			// Assumption: returning false for non-existent rounds is fine.
 			let started_at = self
 				.round(round)
@@ -1388,9 +3375,13 @@ pub mod pallet {
 				.unwrap_or_else(Zero::zero);
 			let block_num = frame_system::Pallet::<T>::block_number();
 
-			started_at > Zero::zero()
-				&& timeout > Zero::zero()
-				&& started_at.saturating_add(timeout) < block_num
+			// A round ending beyond the maximum block number never times out.
+			let timed_out = started_at
+				.checked_add(&timeout)
+				.map(|end| end < block_num)
+				.unwrap_or(false);
+
+			started_at > Zero::zero() && timeout > Zero::zero() && timed_out
 		}
 
 		/// Check whether a round has been updated.
@@ -1421,6 +3412,7 @@ pub mod pallet {
 				Error::<T>::OraclesLimitExceeded
 			);
 			self.config.oracle_count = new_count;
+			let now = frame_system::Pallet::<T>::block_number();
 			for (oracle, admin) in to_add {
 				if let Some(meta) = Oracles::<T>::get(&oracle) {
 					// Make sure the admin is correct in case the oracle
@@ -1438,7 +3430,7 @@ pub mod pallet {
 					);
 				}
 				OracleStatuses::<T>::try_mutate(
-					self.id,
+					self.config.id,
 					&oracle,
 					|maybe_status| -> DispatchResult {
 						// Only allow enabling non-existent or disabled oracles
@@ -1454,13 +3446,19 @@ pub mod pallet {
 							// overwrite the starting and ending round
 							status.starting_round = self.reporting_round_id();
 							status.ending_round = None;
+							status.enabled_since = now;
 						} else {
-							*maybe_status = Some(OracleStatus::new(self.reporting_round_id()));
+							*maybe_status = Some(OracleStatus::new(self.reporting_round_id(), now));
 						}
 						Ok(())
 					},
 				)?;
-				Pallet::<T>::deposit_event(Event::OraclePermissionsUpdated(self.id, oracle, true));
+				OracleFeeds::<T>::insert(&oracle, self.config.id, ());
+				Pallet::<T>::deposit_event(Event::OraclePermissionsUpdated(
+					self.config.id,
+					oracle,
+					true,
+				));
 			}
 
 			Ok(())
@@ -1479,8 +3477,13 @@ pub mod pallet {
 				let mut status = self.status(&d).ok_or(Error::<T>::OracleNotFound)?;
 				ensure!(status.ending_round.is_none(), Error::<T>::OracleDisabled);
 				status.ending_round = Some(self.reporting_round_id());
-				OracleStatuses::<T>::insert(self.id, &d, status);
-				Pallet::<T>::deposit_event(Event::OraclePermissionsUpdated(self.id, d, false));
+				OracleStatuses::<T>::insert(self.config.id, &d, status);
+				OracleFeeds::<T>::remove(&d, self.config.id);
+				Pallet::<T>::deposit_event(Event::OraclePermissionsUpdated(
+					self.config.id,
+					d,
+					false,
+				));
 			}
 			Ok(())
 		}
@@ -1495,28 +3498,16 @@ pub mod pallet {
 			restart_delay: RoundId,
 			timeout: T::BlockNumber,
 		) -> DispatchResult {
-			let (min, max) = submission_count_bounds;
-			ensure!(max >= min, Error::<T>::WrongBounds);
-			// Make sure that both the min and max of submissions is
-			// less or equal to the number of oracles.
-			ensure!(self.oracle_count() >= max, Error::<T>::MaxExceededTotal);
-			// Make sure that at least one oracle can request a new
-			// round.
-			ensure!(
-				self.oracle_count() > restart_delay,
-				Error::<T>::DelayNotBelowCount
-			);
-			if self.oracle_count() > 0 {
-				ensure!(min > 0, Error::<T>::WrongBounds);
-			}
-
-			self.config.payment = payment;
-			self.config.submission_count_bounds = submission_count_bounds;
-			self.config.restart_delay = restart_delay;
-			self.config.timeout = timeout;
+			let mut config = self.config.clone();
+			config.payment = payment;
+			config.submission_count_bounds = submission_count_bounds;
+			config.restart_delay = restart_delay;
+			config.timeout = timeout;
+			validate_feed_config::<T>(&config, self.oracle_count())?;
+			self.config = config;
 
 			Pallet::<T>::deposit_event(Event::RoundDetailsUpdated(
-				self.id,
+				self.config.id,
 				payment,
 				submission_count_bounds,
 				restart_delay,
@@ -1539,8 +3530,8 @@ pub mod pallet {
 			let prune_next = self.config.next_round_to_prune;
 			// only prune if window is exceeded
 			if self.current_window() >= self.config.pruning_window {
-				Rounds::<T>::remove(self.id, prune_next);
-				Details::<T>::remove(self.id, prune_next);
+				Rounds::<T>::remove(self.config.id, prune_next);
+				Details::<T>::remove(self.config.id, prune_next);
 				// update oldest round
 				self.config.next_round_to_prune += RoundId::one();
 				self.config.first_valid_round = Some(self.config.next_round_to_prune);
@@ -1550,7 +3541,26 @@ pub mod pallet {
 			}
 		}
 
-		/// Initialize a new round.
+		/// Prune the given round if it is outside the pruning window.
+		///
+		/// Returns `true` if round was pruned, `false otherwise`
+		fn prune_round(&mut self, round_id: RoundId) -> bool {
+			if round_id == self.config.next_round_to_prune {
+				// keep track of the oldest round
+				return self.prune_oldest();
+			}
+			let outside_window = round_id > self.config.next_round_to_prune
+				&& self.config.latest_round.saturating_sub(round_id) >= self.config.pruning_window;
+			if outside_window && Rounds::<T>::contains_key(self.config.id, round_id) {
+				Rounds::<T>::remove(self.config.id, round_id);
+				Details::<T>::remove(self.config.id, round_id);
+				true
+			} else {
+				false
+			}
+		}
+
+		/// Initialize a new round started by `started_by`.
 		/// Will close the previous one if it is timed out.
 		/// Will prune the oldest round that is outside the pruning window
 		///
@@ -1559,6 +3569,7 @@ pub mod pallet {
 		fn initialize_round(
 			&mut self,
 			new_round_id: RoundId,
+			started_by: &T::AccountId,
 		) -> Result<T::BlockNumber, DispatchError> {
 			self.config.reporting_round = new_round_id;
 
@@ -1566,24 +3577,39 @@ pub mod pallet {
 			if self.is_timed_out(prev_round_id) {
 				self.close_timed_out_round(prev_round_id)?;
 			}
+			let max_open = self.config.max_rounds_open;
+			ensure!(
+				max_open == 0
+					|| (Details::<T>::iter_prefix(self.config.id).count() as u32) < max_open,
+				Error::<T>::TooManyOpenRounds
+			);
 
+			let started_at = frame_system::Pallet::<T>::block_number();
 			Details::<T>::insert(
-				self.id,
+				self.config.id,
 				new_round_id,
 				RoundDetails {
-					submissions: Vec::new(),
+					submissions: Default::default(),
 					submission_count_bounds: self.config.submission_count_bounds,
-					payment: self.config.payment,
+					payment: RoundPaymentOverrides::<T>::take(self.config.id, new_round_id)
+						.unwrap_or(self.config.payment),
 					timeout: self.config.timeout,
+					started_by: started_by.clone(),
+					deadline: self
+						.config
+						.submission_deadline_offset
+						.map(|offset| started_at.saturating_add(offset)),
 				},
 			);
-			let started_at = frame_system::Pallet::<T>::block_number();
-			Rounds::<T>::insert(self.id, new_round_id, Round::new(started_at));
+			Rounds::<T>::insert(self.config.id, new_round_id, Round::new(started_at));
 
 			Ok(started_at)
 		}
 
 		/// Close a timed out round and remove its details.
+		///
+		/// Emits `RoundTimedOut` for empty rounds and `RoundAborted` for rounds that
+		/// did not receive enough submissions to be answered.
 		#[require_transactional]
 		fn close_timed_out_round(&self, timed_out_id: RoundId) -> DispatchResult {
 			let prev_id = timed_out_id.saturating_sub(One::one());
@@ -1594,15 +3620,27 @@ pub mod pallet {
 			let updated_at = frame_system::Pallet::<T>::block_number();
 			timed_out_round.updated_at = Some(updated_at);
 
-			Rounds::<T>::insert(self.id, timed_out_id, timed_out_round);
-			Details::<T>::remove(self.id, timed_out_id);
+			Rounds::<T>::insert(self.config.id, timed_out_id, timed_out_round);
+			if let Some(details) = Details::<T>::take(self.config.id, timed_out_id) {
+				let submissions = details.submissions.len() as u32;
+				let (min_count, _) = details.submission_count_bounds;
+				if submissions == 0 {
+					Pallet::<T>::deposit_event(Event::RoundTimedOut(self.config.id, timed_out_id));
+				} else if submissions < min_count {
+					Pallet::<T>::deposit_event(Event::RoundAborted(
+						self.config.id,
+						timed_out_id,
+						submissions,
+					));
+				}
+			}
 
 			Ok(())
 		}
 
 		/// Store the feed config in storage.
 		fn sync_to_storage(&mut self) {
-			Feeds::<T>::insert(self.id, sp_std::mem::take(&mut self.config));
+			Feeds::<T>::insert(self.config.id, &self.config);
 		}
 	}
 
@@ -1631,10 +3669,79 @@ pub mod pallet {
 		fn feed_mut(id: Self::FeedId) -> Option<Self::MutableFeed> {
 			Feed::load_from(id)
 		}
+
+		/// Check whether the feed exists and contains non-default data.
+		fn is_valid_feed(id: Self::FeedId) -> bool {
+			Feeds::<T>::get(id)
+				.map(|config| config.first_valid_round.is_some())
+				.unwrap_or(false)
+		}
+
+		/// Iterate over the keys of the feed configs.
+		///
+		/// The keys are read lazily from storage.
+		fn all_feed_ids() -> Box<dyn Iterator<Item = Self::FeedId>> {
+			Box::new(Feeds::<T>::iter_keys())
+		}
+
+		/// The fund account of the pallet.
+		fn system_account() -> T::AccountId {
+			Self::fund_account_id()
+		}
+
+		/// Write the answer to `Rounds` and advance `latest_round` if necessary.
+		///
+		/// Does not touch the round details, pay oracles or call the `OnAnswerHandler`.
+		#[cfg(any(test, feature = "testing-utils"))]
+		fn set_answer(feed_id: T::FeedId, round_id: RoundId, value: T::Value) -> DispatchResult {
+			ensure!(round_id > Zero::zero(), Error::<T>::InvalidRound);
+			let mut feed = Feed::<T>::load_from(feed_id).ok_or(Error::<T>::FeedNotFound)?;
+			let updated_at = frame_system::Pallet::<T>::block_number();
+			let started_at = feed.round(round_id).map_or(updated_at, |r| r.started_at);
+			let round = RoundData {
+				round_id,
+				started_at,
+				answer: value,
+				updated_at,
+				answered_in_round: round_id,
+			};
+			Rounds::<T>::insert(feed_id, round_id, round.into_round());
+			feed.config.latest_round = feed.config.latest_round.max(round_id);
+			if feed.config.first_valid_round.is_none() {
+				feed.config.first_valid_round = Some(round_id);
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> ChainlinkAggregatorV3<T::FeedId> for Pallet<T> {
+		/// Block numbers are converted via `block_to_unix_seconds`.
+		fn latest_round_data(feed_id: T::FeedId) -> Option<(u128, i128, u64, u64, u128)> {
+			let feed = Feed::<T>::read_only_from(feed_id)?;
+			if !feed.has_valid_data() {
+				return None;
+			}
+			let data = feed.latest_data();
+			let answer: i128 = data.answer.try_into().ok()?;
+			Some((
+				data.round_id.into(),
+				answer,
+				Self::block_to_unix_seconds(data.started_at),
+				Self::block_to_unix_seconds(data.updated_at),
+				data.answered_in_round.into(),
+			))
+		}
 	}
 
 	impl<T: Config> FeedInterface<T> for Feed<T> {
+		type FeedId = T::FeedId;
 		type Value = T::Value;
+		type Balance = BalanceOf<T>;
+
+		/// Returns the id of the feed as stored in its config.
+		fn id(&self) -> T::FeedId {
+			self.config.id
+		}
 
 		/// Returns the id of the first round that contains non-default data.
 		fn first_valid_round(&self) -> Option<RoundId> {
@@ -1647,23 +3754,78 @@ pub mod pallet {
 		}
 
 		/// Returns the data for a given round.
-		fn data_at(&self, round: RoundId) -> Option<RoundData<T::BlockNumber, T::Value>> {
-			self.round(round)?.try_into().ok()
+		///
+		/// Rounds before the first valid round (e.g. the seed round `0`) are treated as missing.
+		fn data_at(&self, round_id: RoundId) -> Result<RoundDataOf<T>, DispatchError> {
+			ensure!(
+				round_id >= self.config.first_valid_round.unwrap_or(RoundId::MAX),
+				Error::<T>::RoundNotFound
+			);
+			let round = self.round(round_id).ok_or(Error::<T>::RoundNotFound)?;
+			Ok((round_id, round).try_into()?)
 		}
 
 		/// Returns the latest data for the feed.
 		fn latest_data(&self) -> RoundData<T::BlockNumber, T::Value> {
 			let latest_round = self.latest_round();
-			self.data_at(latest_round).unwrap_or_else(|| {
+			// not using `data_at` as the seed round is returned if there is no valid round, yet
+			let data = self
+				.round(latest_round)
+				.ok_or(RoundConversionError::MissingField)
+				.and_then(|round| (latest_round, round).try_into());
+			data.unwrap_or_else(|_| {
 				debug_assert!(false, "The latest round data should always be available.");
 				RoundData::default()
 			})
 		}
 
+		/// Returns the latest data for the feed or why it is not available.
+		fn try_get_latest_data(&self) -> Result<RoundDataOf<T>, FeedError> {
+			if !self.has_valid_data() {
+				return Err(FeedError::NoDataYet);
+			}
+			let latest_round = self.latest_round();
+			let round = self.round(latest_round).ok_or(FeedError::PrunedRound)?;
+			Ok((latest_round, round).try_into()?)
+		}
+
 		/// Returns the configured decimals
 		fn decimals(&self) -> u8 {
 			self.config.decimals
 		}
+
+		/// Feeds created before `created_at` was tracked count from block zero.
+		fn feed_age(&self) -> T::BlockNumber {
+			frame_system::Pallet::<T>::block_number().saturating_sub(self.config.created_at)
+		}
+
+		/// Returns the standard deviation of the submissions of the given round.
+		fn submission_std_dev(&self, round: RoundId) -> Option<T::Value> {
+			std_dev(&self.details(round)?.submissions)
+		}
+
+		/// Reads the status from storage.
+		fn oracle_status(&self, oracle: &T::AccountId) -> Option<OracleStatusOf<T>> {
+			self.status(oracle)
+		}
+
+		/// Walks back from the latest round and stops at the first pruned round, as all
+		/// rounds before it have been pruned as well.
+		fn answers_since(&self, since_round: RoundId) -> Vec<T::Value> {
+			let first = match self.config.first_valid_round {
+				Some(first) => first.max(since_round),
+				None => return Vec::new(),
+			};
+			let mut answers = Vec::new();
+			for round in (first..=self.latest_round()).rev() {
+				match self.round(round) {
+					Some(round) => answers.extend(round.answer),
+					None => break,
+				}
+			}
+			answers.reverse();
+			answers
+		}
 	}
 
 	impl<T: Config> MutableFeedInterface<T> for Feed<T> {
@@ -1680,9 +3842,14 @@ pub mod pallet {
 				self.is_supersedable(self.reporting_round_id()),
 				Error::<T>::RoundNotSupersedable
 			);
-			let started_at = self.initialize_round(new_round)?;
+			let started_at = self.initialize_round(new_round, &requester)?;
 
-			Pallet::<T>::deposit_event(Event::NewRound(self.id, new_round, requester, started_at));
+			Pallet::<T>::deposit_event(Event::NewRound(
+				self.config.id,
+				new_round,
+				requester,
+				started_at,
+			));
 
 			Ok(())
 		}
@@ -1690,24 +3857,43 @@ pub mod pallet {
 
 	/// Trait for the chainlink pallet extrinsic weights.
 	pub trait WeightInfo {
-		fn create_feed(o: u32) -> Weight;
+		fn create_feed(o: u32, t: u32) -> Weight;
 		fn transfer_ownership() -> Weight;
 		fn accept_ownership() -> Weight;
+		fn pallet_admin_override_feed_owner() -> Weight;
 		fn set_pruning_window(n: u32) -> Weight;
+		fn prune_single_round() -> Weight;
+		fn set_deviation_alert() -> Weight;
+		fn set_min_oracle_count() -> Weight;
+		fn set_tags(t: u32) -> Weight;
+		fn deprecate_feed() -> Weight;
+		fn undeprecate_feed() -> Weight;
+		fn set_payment_amount() -> Weight;
+		fn set_timeout() -> Weight;
 		fn submit_opening_round_answers() -> Weight;
 		fn submit_closing_answer(o: u32) -> Weight;
 		fn change_oracles(d: u32, n: u32) -> Weight;
 		fn update_future_rounds() -> Weight;
+		fn update_feed_config() -> Weight;
+		fn set_round_payment() -> Weight;
+		fn close_round() -> Weight;
+		fn close_timed_out_round() -> Weight;
 		fn set_requester() -> Weight;
 		fn remove_requester() -> Weight;
 		fn request_new_round() -> Weight;
 		fn withdraw_payment() -> Weight;
+		fn withdraw_payment_all(o: u32) -> Weight;
 		fn transfer_admin() -> Weight;
+		fn transfer_admin_batch(t: u32) -> Weight;
 		fn accept_admin() -> Weight;
 		fn withdraw_funds() -> Weight;
 		fn reduce_debt() -> Weight;
+		fn prune_all_expired(f: u32, r: u32) -> Weight;
+		fn force_answer() -> Weight;
 		fn transfer_pallet_admin() -> Weight;
 		fn accept_pallet_admin() -> Weight;
+		fn cancel_pallet_admin_transfer() -> Weight;
+		fn force_set_pallet_admin() -> Weight;
 		fn set_feed_creator() -> Weight;
 		fn remove_feed_creator() -> Weight;
 	}
@@ -0,0 +1,248 @@
+//! Storage migrations for the chainlink feed pallet
+//!
+//! Every migration checks the on-chain `StorageVersion` and is a noop if it was
+//! already applied.
+
+pub mod v1 {
+	use codec::{Decode, Encode};
+	use frame_support::{
+		traits::{Get, OnRuntimeUpgrade, StorageVersion},
+		weights::Weight,
+		Parameter, RuntimeDebug,
+	};
+	use sp_runtime::traits::{Hash, Zero};
+	use sp_std::{convert::TryInto, marker::PhantomData, prelude::*};
+
+	use crate::{
+		BalanceOf, Config, Details, FeedConfig, FeedConfigOf, FeedOracle, Feeds,
+		FeedsByDescription, OracleFeeds, OracleMeta, OracleMetaOf, OracleStatus, OracleStatusOf,
+		OracleStatuses, Oracles, Pallet, RoundDetails, RoundDetailsOf, RoundId,
+	};
+
+	/// The feed config of the unversioned storage layout.
+	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldFeedConfig<
+		AccountId: Parameter,
+		Balance: Parameter,
+		BlockNumber: Parameter,
+		Value: Parameter,
+	> {
+		pub owner: AccountId,
+		pub pending_owner: Option<AccountId>,
+		pub submission_value_bounds: (Value, Value),
+		pub submission_count_bounds: (u32, u32),
+		pub payment: Balance,
+		pub timeout: BlockNumber,
+		pub decimals: u8,
+		pub description: Vec<u8>,
+		pub restart_delay: RoundId,
+		pub reporting_round: RoundId,
+		pub latest_round: RoundId,
+		pub first_valid_round: Option<RoundId>,
+		pub oracle_count: u32,
+		pub pruning_window: RoundId,
+		pub next_round_to_prune: RoundId,
+		pub debt: Balance,
+		pub max_debt: Option<Balance>,
+	}
+
+	pub type OldFeedConfigOf<T> = OldFeedConfig<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::Value,
+	>;
+
+	/// The round details of the unversioned storage layout.
+	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldRoundDetails<Balance, BlockNumber, Value> {
+		pub submissions: Vec<Value>,
+		pub submission_count_bounds: (u32, u32),
+		pub payment: Balance,
+		pub timeout: BlockNumber,
+	}
+
+	pub type OldRoundDetailsOf<T> = OldRoundDetails<
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		<T as Config>::Value,
+	>;
+
+	/// The oracle meta data of the unversioned storage layout.
+	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldOracleMeta<AccountId, Balance> {
+		pub withdrawable: Balance,
+		pub admin: AccountId,
+		pub pending_admin: Option<AccountId>,
+	}
+
+	pub type OldOracleMetaOf<T> =
+		OldOracleMeta<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+	/// The oracle status of the unversioned storage layout.
+	#[derive(Clone, Encode, Decode, Default, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldOracleStatus<Value> {
+		pub starting_round: RoundId,
+		pub ending_round: Option<RoundId>,
+		pub last_reported_round: Option<RoundId>,
+		pub last_started_round: Option<RoundId>,
+		pub latest_submission: Option<Value>,
+	}
+
+	pub type OldOracleStatusOf<T> = OldOracleStatus<<T as Config>::Value>;
+
+	fn migrate_config<T: Config>(id: T::FeedId, old: OldFeedConfigOf<T>) -> FeedConfigOf<T> {
+		let mut description = old.description;
+		// `create_feed` made sure the description is within the limit
+		description.truncate(T::StringLimit::get() as usize);
+		FeedConfig {
+			id,
+			owner: old.owner,
+			pending_owner: old.pending_owner,
+			submission_value_bounds: old.submission_value_bounds,
+			submission_count_bounds: old.submission_count_bounds,
+			payment: old.payment,
+			timeout: old.timeout,
+			decimals: old.decimals,
+			description: description.try_into().unwrap_or_default(),
+			restart_delay: old.restart_delay,
+			reporting_round: old.reporting_round,
+			latest_round: old.latest_round,
+			first_valid_round: old.first_valid_round,
+			oracle_count: old.oracle_count,
+			pruning_window: old.pruning_window,
+			next_round_to_prune: old.next_round_to_prune,
+			debt: old.debt,
+			max_debt: old.max_debt,
+			// the new features are disabled for existing feeds
+			deviation_alert_percent: 0,
+			treasury_percent: 0,
+			max_rounds_open: 0,
+			// the creation block of existing feeds is unknown
+			created_at: Zero::zero(),
+			min_oracle_count: 0,
+			tags: Default::default(),
+			submission_deadline_offset: None,
+			deprecated: false,
+			version: 1,
+		}
+	}
+
+	fn migrate_details<T: Config>(old: OldRoundDetailsOf<T>) -> RoundDetailsOf<T> {
+		let mut submissions = old.submissions;
+		// there is at most one submission per oracle
+		submissions.truncate(T::OracleCountLimit::get() as usize);
+		RoundDetails {
+			submissions: submissions.try_into().unwrap_or_default(),
+			submission_count_bounds: old.submission_count_bounds,
+			payment: old.payment,
+			timeout: old.timeout,
+			// the initiator of open rounds is unknown
+			started_by: Pallet::<T>::system_account(),
+			deadline: None,
+		}
+	}
+
+	fn migrate_meta<T: Config>(old: OldOracleMetaOf<T>) -> OracleMetaOf<T> {
+		OracleMeta {
+			// earlier payments are unknown, but the withdrawable ones were earned
+			total_earned: old.withdrawable,
+			total_withdrawn: Zero::zero(),
+			withdrawable: old.withdrawable,
+			admin: old.admin,
+			pending_admin: old.pending_admin,
+		}
+	}
+
+	fn migrate_status<T: Config>(old: OldOracleStatusOf<T>) -> OracleStatusOf<T> {
+		OracleStatus {
+			starting_round: old.starting_round,
+			ending_round: old.ending_round,
+			last_reported_round: old.last_reported_round,
+			last_started_round: old.last_started_round,
+			latest_submission: old.latest_submission,
+			// the history of the oracle before the upgrade is unknown
+			last_reported_block: None,
+			submission_count: 0,
+			enabled_since: Zero::zero(),
+			cumulative_earnings: Zero::zero(),
+		}
+	}
+
+	/// Moves the storage from the unversioned layout to version `1`.
+	///
+	/// Translates the feed configs, round details, oracle meta data and oracle
+	/// statuses to their current types and populates the `FeedsByDescription` and
+	/// `OracleFeeds` indices.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() >= 1 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut reads: Weight = 0;
+			let mut writes: Weight = 0;
+			Feeds::<T>::translate::<OldFeedConfigOf<T>, _>(|id, old| {
+				let config = migrate_config::<T>(id, old);
+				// index the most recently created feed for every description
+				FeedsByDescription::<T>::mutate(
+					T::Hashing::hash_of(&config.description),
+					|indexed| {
+						if indexed.map_or(true, |previous| previous < id) {
+							*indexed = Some(id);
+						}
+					},
+				);
+				reads += 2;
+				writes += 2;
+				Some(config)
+			});
+			Details::<T>::translate::<OldRoundDetailsOf<T>, _>(|_, _, old| {
+				reads += 1;
+				writes += 1;
+				Some(migrate_details::<T>(old))
+			});
+			Oracles::<T>::translate::<OldOracleMetaOf<T>, _>(|_, old| {
+				reads += 1;
+				writes += 1;
+				Some(migrate_meta::<T>(old))
+			});
+			OracleStatuses::<T>::translate::<OldOracleStatusOf<T>, _>(|feed_id, oracle, old| {
+				reads += 1;
+				writes += 1;
+				if old.ending_round.is_none() {
+					OracleFeeds::<T>::insert(&oracle, feed_id, ());
+					writes += 1;
+				}
+				Some(migrate_status::<T>(old))
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			frame_support::ensure!(
+				StorageVersion::get::<Pallet<T>>() < 1,
+				"storage is already at version 1"
+			);
+			Ok(())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			frame_support::ensure!(
+				StorageVersion::get::<Pallet<T>>() == 1,
+				"storage version was not updated"
+			);
+			frame_support::ensure!(
+				Feeds::<T>::iter_keys().all(|id| Feeds::<T>::get(id).is_some()),
+				"feed config could not be decoded"
+			);
+			Ok(())
+		}
+	}
+}
@@ -5,8 +5,10 @@ use frame_support::{
 use pallet_chainlink_feed::*;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
+	transaction_validity::TransactionPriority,
+	DispatchError, DispatchResult,
 };
 
 use frame_system as system;
@@ -25,7 +27,7 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		ChainlinkFeed: pallet_chainlink_feed::{Pallet, Call, Storage, Event<T>},
+		ChainlinkFeed: pallet_chainlink_feed::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
 	}
 );
 
@@ -87,8 +89,21 @@ parameter_types! {
 	pub const FeedPalletId: PalletId = PalletId(*b"linkfeed");
 	pub const MinimumReserve: u64 = MIN_RESERVE;
 	pub const StringLimit: u32 = 15;
+	pub const TagLimit: u32 = 10;
+	pub const MaxTags: u32 = 3;
 	pub const OracleLimit: u32 = 10;
 	pub const FeedLimit: u16 = 10;
+	pub const StalenessThreshold: BlockNumber = 10;
+	pub const HistoryDepth: u32 = 3;
+	pub static MinRequesterDelay: RoundId = 0;
+	pub const MaxInitializationsPerBlock: u32 = 2;
+	pub static MaxValidAnswer: Value = Value::MAX;
+	pub static MaxSubmissionValue: Value = Value::MAX;
+	pub const TreasuryAccount: AccountId = 1337;
+	pub const TimeoutCleanupReward: Balance = 2;
+	pub static NowMillis: u64 = 0;
+	pub const ExpectedBlockTime: u64 = 6_000;
+	pub const UnsignedPriority: TransactionPriority = 1 << 20;
 }
 
 type FeedId = u16;
@@ -100,6 +115,24 @@ impl pallet_chainlink_feed::traits::OnAnswerHandler<Test> for Test {
 	}
 }
 
+impl pallet_chainlink_feed::traits::AnswerValidator<Test> for Test {
+	fn validate(_feed: FeedId, _round: RoundId, answer: Value) -> DispatchResult {
+		if answer > MaxValidAnswer::get() {
+			return Err(DispatchError::Other("answer too high"));
+		}
+		Ok(())
+	}
+}
+
+/// Serves the Unix time set via `NowMillis`.
+pub(crate) struct MockTime;
+
+impl frame_support::traits::UnixTime for MockTime {
+	fn now() -> core::time::Duration {
+		core::time::Duration::from_millis(NowMillis::get())
+	}
+}
+
 impl pallet_chainlink_feed::Config for Test {
 	type Event = Event;
 	type FeedId = FeedId;
@@ -108,9 +141,25 @@ impl pallet_chainlink_feed::Config for Test {
 	type PalletId = FeedPalletId;
 	type MinimumReserve = MinimumReserve;
 	type StringLimit = StringLimit;
+	type TagLimit = TagLimit;
+	type MaxTags = MaxTags;
 	type OnAnswerHandler = Self;
+	type AnswerValidator = Self;
+	type Public = UintAuthorityId;
+	type Signature = TestSignature;
+	type UnsignedPriority = UnsignedPriority;
 	type OracleCountLimit = OracleLimit;
 	type FeedLimit = FeedLimit;
+	type StalenessThreshold = StalenessThreshold;
+	type HistoryDepth = HistoryDepth;
+	type MinRequesterDelay = MinRequesterDelay;
+	type MaxInitializationsPerBlock = MaxInitializationsPerBlock;
+	type MaxSubmissionValue = MaxSubmissionValue;
+	type TreasuryAccount = TreasuryAccount;
+	type TimeoutCleanupReward = TimeoutCleanupReward;
+	type UnixTime = MockTime;
+	type ExpectedBlockTime = ExpectedBlockTime;
+	type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
 	type WeightInfo = ();
 }
 
@@ -126,6 +175,8 @@ pub(crate) struct FeedBuilder {
 	oracles: Option<Vec<(AccountId, AccountId)>>,
 	pruning_window: Option<RoundId>,
 	max_debt: Option<Balance>,
+	min_oracle_count: Option<u32>,
+	tags: Option<Vec<Vec<u8>>>,
 }
 
 impl FeedBuilder {
@@ -183,6 +234,16 @@ impl FeedBuilder {
 		self
 	}
 
+	pub fn min_oracle_count(mut self, c: u32) -> Self {
+		self.min_oracle_count = Some(c);
+		self
+	}
+
+	pub fn tags(mut self, t: Vec<Vec<u8>>) -> Self {
+		self.tags = Some(t);
+		self
+	}
+
 	pub fn build_and_store(self) -> DispatchResultWithPostInfo {
 		let owner = Origin::signed(self.owner.unwrap_or(1));
 		let payment = self.payment.unwrap_or(20);
@@ -208,31 +269,96 @@ impl FeedBuilder {
 			oracles,
 			self.pruning_window,
 			max_debt,
+			self.min_oracle_count.unwrap_or(0),
+			self.tags.unwrap_or_default(),
 		)
 	}
 }
 
-// Build genesis storage according to the mock runtime.
-pub fn new_test_ext() -> sp_io::TestExternalities {
-	let mut t = frame_system::GenesisConfig::default()
-		.build_storage::<Test>()
-		.unwrap();
+/// Builds the test externalities, optionally with feeds created on top of the genesis state.
+///
+/// ```ignore
+/// TestExtBuilder::default()
+/// 	.with_feed(FeedBuilder::new().timeout(5))
+/// 	.build()
+/// 	.execute_with(|| { /* feed 0 exists */ });
+/// ```
+pub(crate) struct TestExtBuilder {
+	feeds: Vec<FeedBuilder>,
+	oracles: Vec<(AccountId, AccountId)>,
+	fund_balance: Balance,
+}
 
-	let pallet_account: AccountId = FeedPalletId::get().into_account();
-	pallet_balances::GenesisConfig::<Test> {
-		balances: vec![(pallet_account, 100 * MIN_RESERVE)],
+impl Default for TestExtBuilder {
+	fn default() -> Self {
+		Self {
+			feeds: Vec::new(),
+			oracles: Vec::new(),
+			fund_balance: 100 * MIN_RESERVE,
+		}
 	}
-	.assimilate_storage(&mut t)
-	.unwrap();
+}
 
-	pallet_chainlink_feed::GenesisConfig::<Test> {
-		pallet_admin: Some(pallet_account),
-		feed_creators: vec![1],
+impl TestExtBuilder {
+	/// Create the feed (with the next free id) after genesis.
+	pub fn with_feed(mut self, feed: FeedBuilder) -> Self {
+		self.feeds.push(feed);
+		self
 	}
-	.assimilate_storage(&mut t)
-	.unwrap();
 
-	t.into()
+	/// Add the oracle to all feeds that do not set their own oracles.
+	pub fn with_oracle(mut self, oracle: AccountId, admin: AccountId) -> Self {
+		self.oracles.push((oracle, admin));
+		self
+	}
+
+	/// Set the genesis balance of the fund account.
+	pub fn with_balance(mut self, fund_amount: Balance) -> Self {
+		self.fund_balance = fund_amount;
+		self
+	}
+
+	/// Build the genesis storage and create the feeds on top of it.
+	pub fn build(self) -> sp_io::TestExternalities {
+		let Self {
+			feeds,
+			oracles,
+			fund_balance,
+		} = self;
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Test>()
+			.unwrap();
+
+		let pallet_account: AccountId = FeedPalletId::get().into_account();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: vec![(pallet_account, fund_balance)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		pallet_chainlink_feed::GenesisConfig::<Test> {
+			pallet_admin: Some(pallet_account),
+			feed_creators: vec![1],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext: sp_io::TestExternalities = t.into();
+		ext.execute_with(|| {
+			for mut feed in feeds {
+				if feed.oracles.is_none() && !oracles.is_empty() {
+					feed.oracles = Some(oracles.clone());
+				}
+				feed.build_and_store().expect("feed should be created");
+			}
+		});
+		ext
+	}
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	TestExtBuilder::default().build()
 }
 
 #[macro_export]
@@ -0,0 +1,166 @@
+//! RPC friendly representations of the pallet types
+//!
+//! The types in this module only use standard Rust types so they can be serialized
+//! to JSON, e.g. as part of a runtime API response.
+use codec::Encode;
+use frame_support::{traits::Get, Parameter};
+use serde::{Deserialize, Serialize};
+use sp_core::hexdisplay::HexDisplay;
+use sp_std::convert::{TryFrom, TryInto};
+
+use crate::{FeedConfig, RoundData, RoundId};
+
+/// Possible errors when converting into the RPC types.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RpcConversionError {
+	/// The description is not valid UTF-8.
+	InvalidDescription,
+	/// A tag is not valid UTF-8.
+	InvalidTag,
+	/// A number does not fit into the RPC type.
+	Overflow,
+}
+
+/// JSON serializable version of [`FeedConfig`].
+///
+/// Can be created from a `FeedConfigOf<T>` via `TryFrom`.
+/// Accounts are represented as the hex encoding of their SCALE encoding.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedConfigRpc {
+	pub id: u64,
+	pub owner: String,
+	pub pending_owner: Option<String>,
+	pub submission_value_bounds: (u128, u128),
+	pub submission_count_bounds: (u32, u32),
+	pub payment: u128,
+	pub timeout: u64,
+	pub decimals: u8,
+	pub description: String,
+	pub restart_delay: RoundId,
+	pub reporting_round: RoundId,
+	pub latest_round: RoundId,
+	pub first_valid_round: Option<RoundId>,
+	pub oracle_count: u32,
+	pub pruning_window: RoundId,
+	pub next_round_to_prune: RoundId,
+	pub debt: u128,
+	pub max_debt: Option<u128>,
+	pub deviation_alert_percent: u8,
+	pub treasury_percent: u8,
+	pub max_rounds_open: u32,
+	pub created_at: u64,
+	pub min_oracle_count: u32,
+	pub tags: Vec<String>,
+	pub submission_deadline_offset: Option<u64>,
+	pub deprecated: bool,
+}
+
+/// JSON serializable version of [`RoundData`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundDataRpc {
+	pub round_id: RoundId,
+	pub started_at: u64,
+	pub answer: u128,
+	pub updated_at: u64,
+	pub answered_in_round: RoundId,
+}
+
+pub(crate) fn account_to_hex<A: Encode>(account: &A) -> String {
+	format!("0x{}", HexDisplay::from(&account.encode()))
+}
+
+fn convert<A: TryInto<B>, B>(a: A) -> Result<B, RpcConversionError> {
+	a.try_into().map_err(|_| RpcConversionError::Overflow)
+}
+
+impl<FeedId, AccountId, Balance, BlockNumber, Value, StringLimit, TagLimit, MaxTags>
+	TryFrom<
+		FeedConfig<FeedId, AccountId, Balance, BlockNumber, Value, StringLimit, TagLimit, MaxTags>,
+	> for FeedConfigRpc
+where
+	FeedId: Parameter + TryInto<u64>,
+	AccountId: Parameter,
+	Balance: Parameter + TryInto<u128>,
+	BlockNumber: Parameter + TryInto<u64>,
+	Value: Parameter + TryInto<u128>,
+	StringLimit: Get<u32>,
+	TagLimit: Get<u32>,
+	MaxTags: Get<u32>,
+{
+	type Error = RpcConversionError;
+
+	fn try_from(
+		config: FeedConfig<
+			FeedId,
+			AccountId,
+			Balance,
+			BlockNumber,
+			Value,
+			StringLimit,
+			TagLimit,
+			MaxTags,
+		>,
+	) -> Result<Self, Self::Error> {
+		let description = String::from_utf8(config.description.into_inner())
+			.map_err(|_| RpcConversionError::InvalidDescription)?;
+		let tags = config
+			.tags
+			.into_inner()
+			.into_iter()
+			.map(|tag| String::from_utf8(tag.into_inner()))
+			.collect::<Result<_, _>>()
+			.map_err(|_| RpcConversionError::InvalidTag)?;
+		let (min_value, max_value) = config.submission_value_bounds;
+		Ok(Self {
+			id: convert(config.id)?,
+			owner: account_to_hex(&config.owner),
+			pending_owner: config.pending_owner.as_ref().map(account_to_hex),
+			submission_value_bounds: (convert(min_value)?, convert(max_value)?),
+			submission_count_bounds: config.submission_count_bounds,
+			payment: convert(config.payment)?,
+			timeout: convert(config.timeout)?,
+			decimals: config.decimals,
+			description,
+			restart_delay: config.restart_delay,
+			reporting_round: config.reporting_round,
+			latest_round: config.latest_round,
+			first_valid_round: config.first_valid_round,
+			oracle_count: config.oracle_count,
+			pruning_window: config.pruning_window,
+			next_round_to_prune: config.next_round_to_prune,
+			debt: convert(config.debt)?,
+			max_debt: config.max_debt.map(convert::<_, u128>).transpose()?,
+			deviation_alert_percent: config.deviation_alert_percent,
+			treasury_percent: config.treasury_percent,
+			max_rounds_open: config.max_rounds_open,
+			created_at: convert(config.created_at)?,
+			min_oracle_count: config.min_oracle_count,
+			tags,
+			submission_deadline_offset: config
+				.submission_deadline_offset
+				.map(convert::<_, u64>)
+				.transpose()?,
+			deprecated: config.deprecated,
+		})
+	}
+}
+
+impl<BlockNumber, Value> TryFrom<RoundData<BlockNumber, Value>> for RoundDataRpc
+where
+	BlockNumber: TryInto<u64>,
+	Value: TryInto<u128>,
+{
+	type Error = RpcConversionError;
+
+	fn try_from(data: RoundData<BlockNumber, Value>) -> Result<Self, Self::Error> {
+		Ok(Self {
+			round_id: data.round_id,
+			started_at: convert(data.started_at)?,
+			answer: convert(data.answer)?,
+			updated_at: convert(data.updated_at)?,
+			answered_in_round: data.answered_in_round,
+		})
+	}
+}
@@ -0,0 +1,245 @@
+//! In-memory `FeedOracle` for tests of pallets depending on the feed pallet
+//!
+//! [`MockFeedOracle`] implements [`FeedOracle`] without requiring the chainlink feed pallet
+//! in the test runtime. Seed it with `MockFeedOracle::set_answer` before running the code
+//! under test:
+//!
+//! ```ignore
+//! impl my_pallet::Config for Test {
+//!     type Oracle = MockFeedOracle<Test>;
+//! }
+//!
+//! MockFeedOracle::<Test>::set_answer(0, 1, 42);
+//! ```
+//!
+//! **Note:** The data is kept in thread local storage and survives the end of an
+//! externalities scope. Call `MockFeedOracle::reset` to start from a clean slate.
+use frame_support::PalletId;
+use sp_runtime::{
+	traits::{AccountIdConversion, UniqueSaturatedInto},
+	DispatchError, DispatchResult,
+};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap, marker::PhantomData, prelude::*};
+
+use crate::{FeedInterface, FeedOracle, MutableFeedInterface, OracleStatus, RoundData, RoundId};
+
+/// Feed id type used by [`MockFeedOracle`].
+pub type MockFeedId = u32;
+/// Value type served by [`MockFeed`].
+pub type MockValue = u128;
+/// Balance type of the oracle statuses of [`MockFeed`].
+pub type MockBalance = u128;
+
+/// Stored state of a single mock feed.
+#[derive(Clone, Default)]
+struct MockFeedState {
+	decimals: u8,
+	first_valid_round: Option<RoundId>,
+	latest_round: RoundId,
+	requested_rounds: u32,
+	rounds: BTreeMap<RoundId, RoundData<u64, MockValue>>,
+}
+
+thread_local! {
+	static FEEDS: RefCell<BTreeMap<MockFeedId, MockFeedState>> = RefCell::new(BTreeMap::new());
+	static FAIL_NEXT_NEW_ROUND: RefCell<bool> = RefCell::new(false);
+}
+
+/// `FeedOracle` backed by in-memory maps.
+pub struct MockFeedOracle<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> MockFeedOracle<T> {
+	/// Record `value` as the answer of `round_id` for the given feed, creating the feed
+	/// if necessary.
+	///
+	/// The round is marked as started and updated at the current block.
+	pub fn set_answer(feed_id: MockFeedId, round_id: RoundId, value: MockValue) {
+		let now: u64 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+		FEEDS.with(|feeds| {
+			let mut feeds = feeds.borrow_mut();
+			let feed = feeds.entry(feed_id).or_default();
+			feed.rounds.insert(
+				round_id,
+				RoundData {
+					round_id,
+					started_at: now,
+					answer: value,
+					updated_at: now,
+					answered_in_round: round_id,
+				},
+			);
+			feed.latest_round = feed.latest_round.max(round_id);
+			feed.first_valid_round = Some(
+				feed.first_valid_round
+					.map_or(round_id, |first| first.min(round_id)),
+			);
+		});
+	}
+
+	/// Set the decimals of the given feed, creating the feed if necessary.
+	pub fn set_decimals(feed_id: MockFeedId, decimals: u8) {
+		FEEDS.with(|feeds| feeds.borrow_mut().entry(feed_id).or_default().decimals = decimals);
+	}
+
+	/// Make the next call of `request_new_round` fail.
+	pub fn fail_next_new_round() {
+		FAIL_NEXT_NEW_ROUND.with(|fail| *fail.borrow_mut() = true);
+	}
+
+	/// Return how many rounds were successfully requested for the given feed.
+	pub fn requested_rounds(feed_id: MockFeedId) -> u32 {
+		FEEDS.with(|feeds| {
+			feeds
+				.borrow()
+				.get(&feed_id)
+				.map_or(0, |feed| feed.requested_rounds)
+		})
+	}
+
+	/// Remove all feeds and pending failures.
+	pub fn reset() {
+		FEEDS.with(|feeds| feeds.borrow_mut().clear());
+		FAIL_NEXT_NEW_ROUND.with(|fail| *fail.borrow_mut() = false);
+	}
+}
+
+impl<T: frame_system::Config> FeedOracle<T> for MockFeedOracle<T> {
+	type FeedId = MockFeedId;
+	type Feed = MockFeed<T>;
+	type MutableFeed = MockFeed<T>;
+
+	fn feed(id: Self::FeedId) -> Option<Self::Feed> {
+		MockFeed::load(id)
+	}
+
+	fn feed_mut(id: Self::FeedId) -> Option<Self::MutableFeed> {
+		MockFeed::load(id)
+	}
+
+	fn is_valid_feed(id: Self::FeedId) -> bool {
+		FEEDS.with(|feeds| {
+			feeds
+				.borrow()
+				.get(&id)
+				.map_or(false, |feed| feed.first_valid_round.is_some())
+		})
+	}
+
+	fn all_feed_ids() -> Box<dyn Iterator<Item = Self::FeedId>> {
+		let ids: Vec<MockFeedId> = FEEDS.with(|feeds| feeds.borrow().keys().copied().collect());
+		Box::new(ids.into_iter())
+	}
+
+	/// An account derived from the `mockfeed` pallet id.
+	fn system_account() -> T::AccountId {
+		PalletId(*b"mockfeed").into_account()
+	}
+
+	/// Same as the inherent `MockFeedOracle::set_answer`, never fails.
+	#[cfg(any(test, feature = "testing-utils"))]
+	fn set_answer(feed_id: MockFeedId, round_id: RoundId, value: MockValue) -> DispatchResult {
+		MockFeedOracle::<T>::set_answer(feed_id, round_id, value);
+		Ok(())
+	}
+}
+
+/// Snapshot of a mock feed as returned by [`MockFeedOracle`].
+///
+/// Changes made via `MutableFeedInterface` are written back immediately.
+pub struct MockFeed<T> {
+	id: MockFeedId,
+	state: MockFeedState,
+	_marker: PhantomData<T>,
+}
+
+impl<T> MockFeed<T> {
+	fn load(id: MockFeedId) -> Option<Self> {
+		let state = FEEDS.with(|feeds| feeds.borrow().get(&id).cloned())?;
+		Some(Self {
+			id,
+			state,
+			_marker: PhantomData,
+		})
+	}
+}
+
+impl<T: frame_system::Config> FeedInterface<T> for MockFeed<T> {
+	type FeedId = MockFeedId;
+	type Value = MockValue;
+	type Balance = MockBalance;
+
+	fn id(&self) -> MockFeedId {
+		self.id
+	}
+
+	fn first_valid_round(&self) -> Option<RoundId> {
+		self.state.first_valid_round
+	}
+
+	fn latest_round(&self) -> RoundId {
+		self.state.latest_round
+	}
+
+	fn data_at(
+		&self,
+		round: RoundId,
+	) -> Result<RoundData<T::BlockNumber, MockValue>, DispatchError> {
+		let data = self
+			.state
+			.rounds
+			.get(&round)
+			.ok_or(DispatchError::Other("MockFeedOracle: no data for round"))?;
+		Ok(RoundData {
+			round_id: data.round_id,
+			started_at: data.started_at.unique_saturated_into(),
+			answer: data.answer,
+			updated_at: data.updated_at.unique_saturated_into(),
+			answered_in_round: data.answered_in_round,
+		})
+	}
+
+	fn latest_data(&self) -> RoundData<T::BlockNumber, MockValue> {
+		self.data_at(self.latest_round()).unwrap_or_default()
+	}
+
+	fn decimals(&self) -> u8 {
+		self.state.decimals
+	}
+
+	/// Mock feeds are considered to be created at block zero.
+	fn feed_age(&self) -> T::BlockNumber {
+		frame_system::Pallet::<T>::block_number()
+	}
+
+	/// The mock does not track individual submissions.
+	fn submission_std_dev(&self, _round: RoundId) -> Option<MockValue> {
+		None
+	}
+
+	/// The mock does not track oracles.
+	fn oracle_status(
+		&self,
+		_oracle: &T::AccountId,
+	) -> Option<OracleStatus<T::BlockNumber, MockValue, MockBalance>> {
+		None
+	}
+}
+
+impl<T: frame_system::Config> MutableFeedInterface<T> for MockFeed<T> {
+	/// Counts the request unless `fail_next_new_round` was called before.
+	fn request_new_round(&mut self, _requester: T::AccountId) -> DispatchResult {
+		let fail = FAIL_NEXT_NEW_ROUND.with(|fail| fail.replace(false));
+		if fail {
+			return Err(DispatchError::Other(
+				"MockFeedOracle: request_new_round failed",
+			));
+		}
+		self.state.requested_rounds = self.state.requested_rounds.saturating_add(1);
+		FEEDS.with(|feeds| {
+			if let Some(feed) = feeds.borrow_mut().get_mut(&self.id) {
+				feed.requested_rounds = self.state.requested_rounds;
+			}
+		});
+		Ok(())
+	}
+}
@@ -7,6 +7,7 @@ use frame_support::{
 	sp_runtime::traits::{One, Zero},
 	traits::Currency,
 };
+use sp_std::convert::TryInto;
 
 type Balances = pallet_balances::Pallet<Test>;
 
@@ -25,10 +26,45 @@ fn feed_creation_should_work() {
 			vec![(1, 4), (2, 4), (3, 4)],
 			None,
 			None,
+			0,
+			vec![],
 		));
+		let config = ChainlinkFeed::feed_config(0).expect("feed should be there");
+		assert_eq!(config.version, crate::CURRENT_VERSION);
 	});
 }
 
+#[test]
+fn test_ext_builder_should_work() {
+	TestExtBuilder::default()
+		.with_oracle(5, 6)
+		.with_oracle(7, 6)
+		.with_feed(FeedBuilder::new().min_submissions(1).restart_delay(0))
+		.with_feed(
+			FeedBuilder::new()
+				.description(b"other".to_vec())
+				.oracles(vec![(2, 4)])
+				.min_submissions(1)
+				.restart_delay(0),
+		)
+		.with_balance(10 * MIN_RESERVE)
+		.build()
+		.execute_with(|| {
+			assert_eq!(ChainlinkFeed::reserve_balance(), 10 * MIN_RESERVE);
+			let mut oracles: Vec<_> = ChainlinkFeed::oracle_statuses_for_feed(0)
+				.map(|(oracle, _)| oracle)
+				.collect();
+			oracles.sort();
+			assert_eq!(oracles, vec![5, 7]);
+			// feeds with their own oracles keep them
+			let oracles: Vec<_> = ChainlinkFeed::oracle_statuses_for_feed(1)
+				.map(|(oracle, _)| oracle)
+				.collect();
+			assert_eq!(oracles, vec![2]);
+			assert_eq!(ChainlinkFeed::oracle(5).unwrap().admin, 6);
+		});
+}
+
 #[test]
 fn feed_creation_failure_cases() {
 	new_test_ext().execute_with(|| {
@@ -64,7 +100,7 @@ fn feed_creation_failure_cases() {
 				.min_submissions(0)
 				.oracles(vec![(1, 2)])
 				.build_and_store(),
-			Error::<Test>::WrongBounds
+			Error::<Test>::ZeroMinSubmissions
 		);
 		assert_noop!(
 			FeedBuilder::new()
@@ -83,6 +119,24 @@ fn feed_creation_failure_cases() {
 		);
 	});
 }
+#[test]
+fn feed_creation_with_zero_min_submissions_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FeedBuilder::new().min_submissions(0).build_and_store(),
+			Error::<Test>::ZeroMinSubmissions
+		);
+		// also rejected for feeds without oracles
+		assert_noop!(
+			FeedBuilder::new()
+				.min_submissions(0)
+				.oracles(vec![])
+				.build_and_store(),
+			Error::<Test>::ZeroMinSubmissions
+		);
+	});
+}
+
 #[test]
 fn submit_should_work() {
 	new_test_ext().execute_with(|| {
@@ -130,15 +184,18 @@ fn submit_should_work() {
 		assert_eq!(
 			details,
 			RoundDetails {
-				submissions: vec![submission, submission],
+				submissions: vec![submission, submission].try_into().unwrap(),
 				submission_count_bounds,
 				payment,
 				timeout,
+				started_by: oracle,
+				deadline: None,
 			}
 		);
 		let oracle_status =
 			ChainlinkFeed::oracle_status(feed_id, oracle).expect("oracle status should be present");
 		assert_eq!(oracle_status.latest_submission, Some(submission));
+		assert_eq!(oracle_status.last_reported_block, Some(0));
 	});
 }
 
@@ -185,6 +242,7 @@ fn on_answer_callback_works() {
 			(
 				0,
 				RoundData {
+					round_id: 1,
 					started_at: 1,
 					answer: 42,
 					updated_at: 1,
@@ -232,9 +290,10 @@ fn details_are_cleared() {
 			let round = ChainlinkFeed::round(feed_id, r).unwrap();
 			assert_eq!(round.answer, Some(answer));
 			let details = ChainlinkFeed::round_details(feed_id, r).unwrap();
-			assert_eq!(details.submissions, vec![submission, submission]);
+			assert_eq!(details.submissions.to_vec(), vec![submission, submission]);
 			let oracle_status = ChainlinkFeed::oracle_status(feed_id, oracle).unwrap();
 			assert_eq!(oracle_status.latest_submission, Some(submission));
+			assert_eq!(oracle_status.last_reported_block, Some(0));
 		}
 		{
 			// round 2
@@ -258,9 +317,10 @@ fn details_are_cleared() {
 			let round = ChainlinkFeed::round(feed_id, r).unwrap();
 			assert_eq!(round.answer, Some(answer));
 			let details = ChainlinkFeed::round_details(feed_id, r).unwrap();
-			assert_eq!(details.submissions, vec![submission, submission]);
+			assert_eq!(details.submissions.to_vec(), vec![submission, submission]);
 			let oracle_status = ChainlinkFeed::oracle_status(feed_id, oracle).unwrap();
 			assert_eq!(oracle_status.latest_submission, Some(submission));
+			assert_eq!(oracle_status.last_reported_block, Some(0));
 			// old round details should be gone
 			assert_eq!(ChainlinkFeed::round_details(feed_id, 1), None);
 		}
@@ -308,6 +368,53 @@ fn submit_failure_cases() {
 	});
 }
 
+#[test]
+fn submit_should_enforce_valid_rounds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let submission = 42;
+		assert_ok!(FeedBuilder::new().timeout(1).build_and_store());
+
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(2),
+			feed_id,
+			1,
+			submission
+		));
+		// round 1 is neither answered nor timed out
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(3), feed_id, 2, submission),
+			Error::<Test>::NotSupersedable
+		);
+		// only the current and the next round are valid
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(3), feed_id, 3, submission),
+			Error::<Test>::InvalidRound
+		);
+
+		// a timed out round can be superseded
+		System::set_block_number(3);
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(3),
+			feed_id,
+			2,
+			submission
+		));
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(2),
+			feed_id,
+			2,
+			submission
+		));
+		// the previous round is not valid once the current one is answered
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(4), feed_id, 1, submission),
+			Error::<Test>::InvalidRound
+		);
+	});
+}
+
 #[test]
 fn change_oracles_should_work() {
 	new_test_ext().execute_with(|| {
@@ -328,6 +435,10 @@ fn change_oracles_should_work() {
 		let owner = 1;
 		let feed = ChainlinkFeed::feed_config(feed_id).expect("feed should be there");
 		assert_eq!(feed.oracle_count, 3);
+		assert_eq!(
+			feed.oracle_count,
+			ChainlinkFeed::active_oracle_count(feed_id)
+		);
 
 		let round = 1;
 		let submission = 42;
@@ -431,7 +542,7 @@ fn change_oracles_should_work() {
 		{
 			tx_assert_ok!(ChainlinkFeed::feed_mut(feed_id)
 				.unwrap()
-				.request_new_round(AccountId::default()));
+				.request_new_round(ChainlinkFeed::system_account()));
 		}
 		// successfully change oracles
 		assert_ok!(ChainlinkFeed::change_oracles(
@@ -458,6 +569,10 @@ fn change_oracles_should_work() {
 
 		let feed = ChainlinkFeed::feed_config(feed_id).expect("feed should be there");
 		assert_eq!(feed.oracle_count, 4);
+		assert_eq!(
+			feed.oracle_count,
+			ChainlinkFeed::active_oracle_count(feed_id)
+		);
 		assert_eq!(Oracles::<Test>::iter().count(), 6);
 		assert_eq!(OracleStatuses::<Test>::iter().count(), 6);
 		for o in to_disable.iter() {
@@ -487,11 +602,105 @@ fn change_oracles_should_work() {
 			last_reported_round: Some(1),
 			last_started_round: Some(1),
 			latest_submission: Some(submission),
+			last_reported_block: Some(0),
+			submission_count: 1,
+			enabled_since: 0,
+			cumulative_earnings: 20,
 		};
 		assert_eq!(
 			ChainlinkFeed::oracle_status(feed_id, oracle),
 			Some(expected_status)
 		);
+		let feed = ChainlinkFeed::feed_config(feed_id).expect("feed should be there");
+		assert_eq!(feed.oracle_count, 5);
+		assert_eq!(
+			feed.oracle_count,
+			ChainlinkFeed::active_oracle_count(feed_id)
+		);
+	});
+}
+
+#[test]
+fn change_oracles_should_remove_statuses_of_empty_feeds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (owner, other_feed) = (1, 1);
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.description(b"other".to_vec())
+			.build_and_store());
+		let feed_id = 0;
+
+		// statuses are kept while oracles are left
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![2, 3],
+			vec![],
+		));
+		assert_eq!(OracleStatuses::<Test>::iter_prefix(feed_id).count(), 3);
+
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![4],
+			vec![],
+		));
+		assert_eq!(OracleStatuses::<Test>::iter_prefix(feed_id).count(), 0);
+		assert_eq!(OracleStatuses::<Test>::iter_prefix(other_feed).count(), 3);
+		let ev = mock::Event::ChainlinkFeed(crate::Event::AllOraclesRemoved(feed_id));
+		assert!(System::events().iter().any(|e| e.event == ev));
+		assert_ok!(ChainlinkFeed::check_storage_consistency());
+
+		// oracles can be added again
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![],
+			vec![(2, 4)],
+		));
+		assert_eq!(ChainlinkFeed::active_oracle_count(feed_id), 1);
+	});
+}
+
+#[test]
+fn change_oracles_should_keep_statuses_of_open_rounds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let owner = 1;
+		let oracle = 2;
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		// the round stays open with a single submission
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			1,
+			42
+		));
+
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![2, 3, 4],
+			vec![],
+		));
+		assert_eq!(OracleStatuses::<Test>::iter_prefix(feed_id).count(), 3);
+		let ev = mock::Event::ChainlinkFeed(crate::Event::AllOraclesRemoved(feed_id));
+		assert!(!System::events().iter().any(|e| e.event == ev));
+
+		// a re-added oracle cannot submit to the open round again
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![],
+			vec![(oracle, 4)],
+		));
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(oracle), feed_id, 1, 42),
+			Error::<Test>::ReportingOrder
+		);
 	});
 }
 
@@ -583,7 +792,7 @@ fn update_future_rounds_should_work() {
 				new_delay,
 				new_timeout,
 			),
-			Error::<Test>::WrongBounds
+			Error::<Test>::ZeroMinSubmissions
 		);
 
 		// successful update
@@ -731,10 +940,12 @@ fn request_new_round_should_work() {
 		assert_eq!(
 			details,
 			RoundDetails {
-				submissions: Vec::new(),
+				submissions: Default::default(),
 				submission_count_bounds,
 				payment,
 				timeout,
+				started_by: requester,
+				deadline: None,
 			}
 		);
 		let requester_meta =
@@ -806,6 +1017,52 @@ fn requester_permissions() {
 	});
 }
 
+#[test]
+fn list_requesters_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = 1;
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.description(b"other".to_vec())
+			.build_and_store());
+		let (feed_id, other_feed) = (0, 1);
+		assert_eq!(ChainlinkFeed::list_requesters(feed_id), vec![]);
+
+		for (requester, delay) in [(22, 1), (23, 2), (24, 3)].iter() {
+			assert_ok!(ChainlinkFeed::set_requester(
+				Origin::signed(owner),
+				feed_id,
+				*requester,
+				*delay
+			));
+		}
+		assert_ok!(ChainlinkFeed::set_requester(
+			Origin::signed(owner),
+			other_feed,
+			25,
+			1
+		));
+		assert_ok!(ChainlinkFeed::remove_requester(
+			Origin::signed(owner),
+			feed_id,
+			23
+		));
+
+		let mut requesters = ChainlinkFeed::list_requesters(feed_id);
+		requesters.sort_by_key(|(account, _)| *account);
+		let requester = |delay| Requester {
+			delay,
+			last_started_round: None,
+		};
+		assert_eq!(requesters, vec![(22, requester(1)), (24, requester(3))]);
+		assert_eq!(
+			ChainlinkFeed::list_requesters(other_feed),
+			vec![(25, requester(1))]
+		);
+	});
+}
+
 #[test]
 fn transfer_ownership_should_work() {
 	new_test_ext().execute_with(|| {
@@ -889,6 +1146,7 @@ fn feed_oracle_trait_should_work() {
 			assert_eq!(
 				feed.latest_data(),
 				RoundData {
+					round_id: 1,
 					answer: 42,
 					started_at: 0,
 					updated_at: 0,
@@ -896,7 +1154,7 @@ fn feed_oracle_trait_should_work() {
 				}
 			);
 
-			tx_assert_ok!(feed.request_new_round(AccountId::default()));
+			tx_assert_ok!(feed.request_new_round(ChainlinkFeed::system_account()));
 		}
 		let round_id = 2;
 		let round =
@@ -911,6 +1169,67 @@ fn feed_oracle_trait_should_work() {
 	});
 }
 
+#[test]
+fn try_get_latest_data_should_work() {
+	TestExtBuilder::default()
+		.with_feed(FeedBuilder::new())
+		.build()
+		.execute_with(|| {
+			let feed_id = 0;
+			assert_eq!(
+				ChainlinkFeed::feed(feed_id).unwrap().try_get_latest_data(),
+				Err(FeedError::NoDataYet)
+			);
+
+			assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+			assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 42));
+			let data = ChainlinkFeed::feed(feed_id)
+				.unwrap()
+				.try_get_latest_data()
+				.expect("feed should have data");
+			assert_eq!(data.round_id, 1);
+			assert_eq!(data.answer, 42);
+
+			// simulate the latest round being pruned
+			Rounds::<Test>::remove(feed_id, 1);
+			assert_eq!(
+				ChainlinkFeed::feed(feed_id).unwrap().try_get_latest_data(),
+				Err(FeedError::PrunedRound)
+			);
+		});
+}
+
+#[test]
+fn feed_interface_oracle_status_should_work() {
+	TestExtBuilder::default()
+		.with_feed(FeedBuilder::new())
+		.build()
+		.execute_with(|| {
+			let feed_id = 0;
+			assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+
+			let feed = ChainlinkFeed::feed(feed_id).expect("feed should be there");
+			assert_eq!(
+				feed.oracle_status(&2),
+				ChainlinkFeed::oracle_status(feed_id, 2)
+			);
+			let status = feed.oracle_status(&2).expect("oracle should be enabled");
+			assert_eq!(status.last_reported_round, Some(1));
+			assert_eq!(status.ending_round, None);
+			assert_eq!(feed.oracle_status(&42), None);
+			drop(feed);
+
+			assert_ok!(ChainlinkFeed::change_oracles(
+				Origin::signed(1),
+				feed_id,
+				vec![3],
+				vec![],
+			));
+			let feed = ChainlinkFeed::feed(feed_id).expect("feed should be there");
+			assert!(feed.oracle_status(&3).unwrap().ending_round.is_some());
+		});
+}
+
 #[test]
 fn payment_withdrawal_should_work() {
 	new_test_ext().execute_with(|| {
@@ -962,6 +1281,9 @@ fn payment_withdrawal_should_work() {
 			recipient,
 			amount
 		));
+		let meta = ChainlinkFeed::oracle(oracle).unwrap();
+		assert_eq!(meta.withdrawable, 0);
+		assert_eq!(meta.total_withdrawn, amount);
 	});
 }
 
@@ -1040,6 +1362,8 @@ fn auto_prune_should_work() {
 				vec![(1, 4), (2, 4), (3, 4)],
 				Some(0),
 				None,
+				0,
+				vec![],
 			),
 			Error::<Test>::CannotPruneRoundZero
 		);
@@ -1188,9 +1512,10 @@ fn feed_life_cylce() {
 		let submission_value_bounds = (1, 1_000);
 		let submission_count_bounds = (1, 3);
 		let decimals = 5;
-		let description = b"desc".to_vec();
+		let description = b"desc".to_vec().try_into().unwrap();
 		let restart_delay = 1;
 		let new_config = FeedConfig {
+			id,
 			owner,
 			pending_owner: None,
 			payment,
@@ -1208,6 +1533,15 @@ fn feed_life_cylce() {
 			pruning_window: RoundId::MAX,
 			debt: Zero::zero(),
 			max_debt: None,
+			deviation_alert_percent: 0,
+			treasury_percent: 0,
+			max_rounds_open: 0,
+			created_at: 0,
+			min_oracle_count: 0,
+			tags: Default::default(),
+			submission_deadline_offset: None,
+			deprecated: false,
+			version: crate::CURRENT_VERSION,
 		};
 		let oracles = vec![(2, 2), (3, 3), (4, 4)];
 		{
@@ -1252,7 +1586,7 @@ fn feed_life_cylce() {
 		);
 		{
 			let mut feed = ChainlinkFeed::feed_mut(id).expect("feed should be there");
-			tx_assert_ok!(feed.request_new_round(AccountId::default()));
+			tx_assert_ok!(feed.request_new_round(ChainlinkFeed::system_account()));
 		}
 		assert_eq!(ChainlinkFeed::feed_config(id).unwrap().reporting_round, 1);
 	});
@@ -1363,3 +1697,3452 @@ fn allows_submissions_until_max_debt() {
 		);
 	});
 }
+
+#[test]
+fn stale_feeds_are_detected() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let oracle = 2;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.oracles(vec![(oracle, 4), (3, 4)])
+			.build_and_store());
+
+		let threshold = StalenessThreshold::get();
+		// the seed round counts as the latest update until the first answer
+		assert!(ChainlinkFeed::stale_feeds(threshold).is_empty());
+		assert_eq!(
+			ChainlinkFeed::stale_feeds(threshold + 1),
+			vec![(feed_id, threshold + 1)]
+		);
+
+		System::set_block_number(threshold);
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			1,
+			42
+		));
+		assert!(ChainlinkFeed::stale_feeds(2 * threshold).is_empty());
+		assert_eq!(
+			ChainlinkFeed::stale_feeds(2 * threshold + 5),
+			vec![(feed_id, threshold + 5)]
+		);
+	});
+}
+
+#[test]
+fn unsigned_submissions_should_work() {
+	use codec::Encode;
+	use frame_support::unsigned::ValidateUnsigned;
+	use sp_runtime::{
+		testing::{TestSignature, UintAuthorityId},
+		transaction_validity::{InvalidTransaction, TransactionSource},
+	};
+
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let round_id = 1;
+		let oracle = 2;
+		let submission = 42;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.oracles(vec![(oracle, 4), (3, 4)])
+			.build_and_store());
+
+		let payload = (feed_id, round_id, submission).encode();
+		let call = |signature: TestSignature, public: u64| {
+			crate::Call::submit_unsigned(
+				feed_id,
+				round_id,
+				submission,
+				signature,
+				UintAuthorityId(public),
+			)
+		};
+		let source = TransactionSource::External;
+
+		// signature does not match the oracle
+		let wrong_signer = call(TestSignature(3, payload.clone()), oracle);
+		assert_eq!(
+			ChainlinkFeed::validate_unsigned(source, &wrong_signer),
+			InvalidTransaction::BadProof.into()
+		);
+		// signature over a different payload
+		let wrong_payload = call(TestSignature(oracle, vec![1, 2, 3]), oracle);
+		assert_eq!(
+			ChainlinkFeed::validate_unsigned(source, &wrong_payload),
+			InvalidTransaction::BadProof.into()
+		);
+		// correctly signed but not an oracle of the feed
+		let not_oracle = 1337;
+		let unknown = call(TestSignature(not_oracle, payload.clone()), not_oracle);
+		assert_eq!(
+			ChainlinkFeed::validate_unsigned(source, &unknown),
+			InvalidTransaction::Stale.into()
+		);
+
+		let valid = call(TestSignature(oracle, payload.clone()), oracle);
+		assert!(ChainlinkFeed::validate_unsigned(source, &valid).is_ok());
+		assert_ok!(ChainlinkFeed::submit_unsigned(
+			Origin::none(),
+			feed_id,
+			round_id,
+			submission,
+			TestSignature(oracle, payload),
+			UintAuthorityId(oracle),
+		));
+		let round = ChainlinkFeed::round(feed_id, round_id).expect("round should be present");
+		assert_eq!(round.answer, Some(submission));
+		// the oracle already reported for this round
+		assert_eq!(
+			ChainlinkFeed::validate_unsigned(source, &valid),
+			InvalidTransaction::Stale.into()
+		);
+	});
+}
+
+#[test]
+fn moving_average_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let oracle = 2;
+		let owner = 1;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.min_submissions(1)
+			.restart_delay(0)
+			.oracles(vec![(oracle, 4)])
+			.build_and_store());
+		{
+			// no answered rounds yet
+			let feed = ChainlinkFeed::feed(feed_id).expect("feed should be there");
+			assert_eq!(feed.moving_average(3), None);
+		}
+
+		for (r, submission) in (1..=4).zip(vec![10, 20, 30, 40]) {
+			System::set_block_number(r as u64);
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(oracle),
+				feed_id,
+				r,
+				submission
+			));
+		}
+		{
+			let feed = ChainlinkFeed::feed(feed_id).expect("feed should be there");
+			// empty window
+			assert_eq!(feed.moving_average(0), None);
+			assert_eq!(feed.moving_average(2), Some(35));
+			// window larger than the available history
+			assert_eq!(feed.moving_average(10), Some(25));
+		}
+
+		// prune rounds 1 and 2
+		assert_ok!(ChainlinkFeed::set_pruning_window(
+			Origin::signed(owner),
+			feed_id,
+			2
+		));
+		let feed = ChainlinkFeed::feed(feed_id).expect("feed should be there");
+		assert_eq!(ChainlinkFeed::round(feed_id, 1), None);
+		// window spanning pruned rounds
+		assert_eq!(feed.moving_average(4), Some(35));
+	});
+}
+
+#[test]
+fn submission_std_dev_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let round_id = 1;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(3)
+			.oracles(vec![(2, 4), (3, 4), (5, 4)])
+			.build_and_store());
+		{
+			let feed = ChainlinkFeed::feed(feed_id).expect("feed should be there");
+			assert_eq!(feed.submission_std_dev(round_id), None);
+		}
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(2),
+			feed_id,
+			round_id,
+			40
+		));
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(3),
+			feed_id,
+			round_id,
+			60
+		));
+		let feed = ChainlinkFeed::feed(feed_id).expect("feed should be there");
+		assert_eq!(feed.submission_std_dev(round_id), Some(10));
+	});
+}
+
+#[test]
+fn answer_deviation_alert_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let owner = 1;
+		let oracle = 2;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.min_submissions(1)
+			.restart_delay(0)
+			.oracles(vec![(oracle, 4)])
+			.build_and_store());
+		assert_noop!(
+			ChainlinkFeed::set_deviation_alert(Origin::signed(123), feed_id, 10),
+			Error::<Test>::NotFeedOwner
+		);
+		assert_ok!(ChainlinkFeed::set_deviation_alert(
+			Origin::signed(owner),
+			feed_id,
+			10
+		));
+
+		let deviation_alerts = || {
+			System::events()
+				.into_iter()
+				.filter_map(|r| {
+					if let mock::Event::ChainlinkFeed(crate::Event::AnswerDeviationHigh(
+						feed,
+						round,
+						prev,
+						new,
+						threshold,
+					)) = r.event
+					{
+						Some((feed, round, prev, new, threshold))
+					} else {
+						None
+					}
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// no alert for the first answer
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			1,
+			100
+		));
+		assert!(deviation_alerts().is_empty());
+		// exactly at the threshold
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			2,
+			110
+		));
+		assert!(deviation_alerts().is_empty());
+		// just above the threshold
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			3,
+			122
+		));
+		assert_eq!(deviation_alerts(), vec![(feed_id, 3, 110, 122, 10)]);
+		// the feed keeps working
+		assert_eq!(ChainlinkFeed::round(feed_id, 3).unwrap().answer, Some(122));
+	});
+}
+
+#[test]
+fn set_payment_amount_and_timeout_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let owner = 1;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.payment(20)
+			.timeout(1)
+			.build_and_store());
+		let before = ChainlinkFeed::feed_config(feed_id).unwrap();
+
+		assert_noop!(
+			ChainlinkFeed::set_payment_amount(Origin::signed(2), feed_id, 33),
+			Error::<Test>::NotFeedOwner
+		);
+		assert_noop!(
+			ChainlinkFeed::set_timeout(Origin::signed(owner), 123, 5),
+			Error::<Test>::FeedNotFound
+		);
+
+		assert_ok!(ChainlinkFeed::set_payment_amount(
+			Origin::signed(owner),
+			feed_id,
+			33
+		));
+		assert_ok!(ChainlinkFeed::set_timeout(
+			Origin::signed(owner),
+			feed_id,
+			5
+		));
+		let config = ChainlinkFeed::feed_config(feed_id).unwrap();
+		assert_eq!(config.payment, 33);
+		assert_eq!(config.timeout, 5);
+		// the other round parameters are untouched
+		assert_eq!(
+			config.submission_count_bounds,
+			before.submission_count_bounds
+		);
+		assert_eq!(config.restart_delay, before.restart_delay);
+
+		let payment_event =
+			mock::Event::ChainlinkFeed(crate::Event::PaymentAmountUpdated(feed_id, 33));
+		assert!(System::events().iter().any(|e| e.event == payment_event));
+		let timeout_event = mock::Event::ChainlinkFeed(crate::Event::TimeoutUpdated(feed_id, 5));
+		assert!(System::events().iter().any(|e| e.event == timeout_event));
+	});
+}
+
+#[test]
+fn update_feed_config_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		let oracles = vec![(2, 4), (3, 4), (5, 4)];
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.payment(20)
+			.timeout(10)
+			.min_submissions(2)
+			.oracles(oracles)
+			.build_and_store());
+		let old_config = ChainlinkFeed::feed_config(feed_id).expect("feed should be there");
+
+		assert_noop!(
+			ChainlinkFeed::update_feed_config(Origin::signed(owner), 123, Default::default()),
+			Error::<Test>::FeedNotFound
+		);
+		assert_noop!(
+			ChainlinkFeed::update_feed_config(Origin::signed(123), feed_id, Default::default()),
+			Error::<Test>::NotFeedOwner
+		);
+		assert_noop!(
+			ChainlinkFeed::update_feed_config(
+				Origin::signed(owner),
+				feed_id,
+				FeedConfigUpdates {
+					submission_value_bounds: Some((10, 5)),
+					..Default::default()
+				}
+			),
+			Error::<Test>::WrongBounds
+		);
+		assert_noop!(
+			ChainlinkFeed::update_feed_config(
+				Origin::signed(owner),
+				feed_id,
+				FeedConfigUpdates {
+					description: Some(b"waaaaaaaaaaaaaaaaay too long".to_vec()),
+					..Default::default()
+				}
+			),
+			Error::<Test>::DescriptionTooLong
+		);
+		// a failing update does not apply any of the other changes
+		assert_noop!(
+			ChainlinkFeed::update_feed_config(
+				Origin::signed(owner),
+				feed_id,
+				FeedConfigUpdates {
+					payment: Some(30),
+					submission_count_bounds: Some((0, 3)),
+					..Default::default()
+				}
+			),
+			Error::<Test>::ZeroMinSubmissions
+		);
+
+		// nothing changes if no field is set
+		tx_assert_ok!(ChainlinkFeed::update_feed_config(
+			Origin::signed(owner),
+			feed_id,
+			Default::default()
+		));
+		assert_eq!(
+			ChainlinkFeed::feed_config(feed_id),
+			Some(old_config.clone())
+		);
+
+		tx_assert_ok!(ChainlinkFeed::update_feed_config(
+			Origin::signed(owner),
+			feed_id,
+			FeedConfigUpdates {
+				payment: Some(30),
+				submission_value_bounds: Some((5, 500)),
+				description: Some(b"new desc".to_vec()),
+				..Default::default()
+			}
+		));
+		let config = ChainlinkFeed::feed_config(feed_id).expect("feed should be there");
+		assert_eq!(config.payment, 30);
+		assert_eq!(config.submission_value_bounds, (5, 500));
+		assert_eq!(config.description.to_vec(), b"new desc".to_vec());
+		// untouched fields keep their values
+		assert_eq!(config.timeout, old_config.timeout);
+		assert_eq!(
+			config.submission_count_bounds,
+			old_config.submission_count_bounds
+		);
+	});
+}
+
+#[test]
+fn feed_config_builder_should_work() {
+	use crate::builder::{BuilderError, FeedConfigBuilder};
+
+	new_test_ext().execute_with(|| {
+		let owner = 1;
+		let oracles = vec![(2, 4), (3, 4), (5, 4)];
+		let builder = || {
+			FeedConfigBuilder::<Test>::new(owner)
+				.payment(20)
+				.timeout(10)
+				.value_bounds(1, 1_000)
+				.decimals(5)
+				.description(b"desc".to_vec())
+				.oracles(oracles.clone())
+		};
+
+		assert_eq!(
+			builder()
+				.description(b"waaaaaaaaaaaaaaaaay too long".to_vec())
+				.build()
+				.err(),
+			Some(BuilderError::DescriptionTooLong)
+		);
+		assert_eq!(
+			builder().pruning_window(0).build().err(),
+			Some(BuilderError::CannotPruneRoundZero)
+		);
+		assert_eq!(
+			builder().value_bounds(10, 1).build().err(),
+			Some(BuilderError::WrongBounds)
+		);
+		assert_eq!(
+			builder().count_bounds(0, 2).build().err(),
+			Some(BuilderError::ZeroMinSubmissions)
+		);
+		assert_eq!(
+			builder().count_bounds(1, 4).build().err(),
+			Some(BuilderError::MaxExceededTotal)
+		);
+		assert_eq!(
+			builder().restart_delay(3).build().err(),
+			Some(BuilderError::DelayNotBelowCount)
+		);
+
+		let (config, feed_oracles) = builder()
+			.count_bounds(2, 3)
+			.restart_delay(2)
+			.build()
+			.expect("config should be valid");
+		assert_eq!(feed_oracles, oracles);
+		assert_eq!(config.owner, owner);
+		assert_eq!(config.submission_count_bounds, (2, 3));
+		assert_eq!(config.oracle_count, 0);
+
+		// the builder does not touch storage
+		assert_eq!(ChainlinkFeed::feed_config(0), None);
+
+		let feed_id = 0;
+		{
+			let mut feed = Feed::<Test>::new(feed_id, config);
+			tx_assert_ok!(feed.add_oracles(feed_oracles));
+		}
+		let stored = ChainlinkFeed::feed_config(feed_id).expect("feed should be there");
+		assert_eq!(stored.oracle_count, 3);
+	});
+}
+
+#[test]
+fn feed_config_builder_should_default_to_max_submission_value() {
+	use crate::builder::FeedConfigBuilder;
+
+	new_test_ext().execute_with(|| {
+		MaxSubmissionValue::set(&500);
+		let (config, _) = FeedConfigBuilder::<Test>::new(1)
+			.oracles(vec![(2, 4), (3, 4)])
+			.build()
+			.expect("default bounds should be valid");
+		assert_eq!(config.submission_value_bounds, (0, 500));
+		MaxSubmissionValue::set(&u64::MAX);
+	});
+}
+
+#[test]
+fn is_valid_feed_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let oracle = 2;
+		assert!(!ChainlinkFeed::is_valid_feed(feed_id));
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.oracles(vec![(oracle, 4), (3, 4)])
+			.build_and_store());
+		// the feed exists but has no data, yet
+		assert!(!ChainlinkFeed::is_valid_feed(feed_id));
+		assert!(!ChainlinkFeed::feed(feed_id).unwrap().has_valid_data());
+
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			1,
+			42
+		));
+		assert!(ChainlinkFeed::is_valid_feed(feed_id));
+		assert!(ChainlinkFeed::feed(feed_id).unwrap().has_valid_data());
+	});
+}
+
+#[test]
+fn latest_answer_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let oracle = 2;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.oracles(vec![(oracle, 4), (3, 4)])
+			.build_and_store());
+		{
+			let feed = ChainlinkFeed::feed(feed_id).expect("feed should be there");
+			// the seed data is not exposed as an answer
+			assert_eq!(feed.latest_data().answer, 0);
+			assert_eq!(feed.latest_answer(), None);
+		}
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			1,
+			42
+		));
+		let feed = ChainlinkFeed::feed(feed_id).expect("feed should be there");
+		assert_eq!(feed.latest_answer(), Some(42));
+	});
+}
+
+#[test]
+fn prune_all_expired_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let oracle = 2;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.restart_delay(0)
+			.oracles(vec![(oracle, 4), (3, 4)])
+			.build_and_store());
+		assert_ok!(FeedBuilder::new().build_and_store());
+		for round in 1..=5 {
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(oracle),
+				feed_id,
+				round,
+				42
+			));
+		}
+		// shrink the window without pruning
+		Feeds::<Test>::mutate(feed_id, |config| {
+			config.as_mut().unwrap().pruning_window = 2;
+		});
+
+		let pallet_admin = ChainlinkFeed::pallet_admin();
+		let (feed_count, round_count) = (2, 3);
+		assert_noop!(
+			ChainlinkFeed::prune_all_expired(Origin::signed(123), feed_count, round_count),
+			Error::<Test>::NotPalletAdmin
+		);
+		// the witnesses have to cover the actual counts
+		assert_noop!(
+			ChainlinkFeed::prune_all_expired(Origin::signed(pallet_admin), 1, round_count),
+			Error::<Test>::InvalidWitness
+		);
+		assert_noop!(
+			ChainlinkFeed::prune_all_expired(Origin::signed(pallet_admin), feed_count, 2),
+			Error::<Test>::InvalidWitness
+		);
+		let post_info =
+			ChainlinkFeed::prune_all_expired(Origin::signed(pallet_admin), 10, 20).unwrap();
+		// the unused weight is refunded
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::prune_all_expired(
+				feed_count,
+				round_count
+			))
+		);
+
+		for round in 1..=3 {
+			assert_eq!(ChainlinkFeed::round(feed_id, round), None);
+		}
+		assert!(ChainlinkFeed::round(feed_id, 4).is_some());
+		let config = ChainlinkFeed::feed_config(feed_id).unwrap();
+		assert_eq!(config.next_round_to_prune, 4);
+		assert_eq!(config.first_valid_round, Some(4));
+
+		let pruned: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|r| match r.event {
+				mock::Event::ChainlinkFeed(crate::Event::PruningCompleted(feed, rounds)) => {
+					Some((feed, rounds))
+				}
+				_ => None,
+			})
+			.collect();
+		assert_eq!(pruned, vec![(feed_id, 3)]);
+	});
+}
+
+#[test]
+fn data_freshness_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let oracle = 2;
+		let max_age = 5;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.oracles(vec![(oracle, 4), (3, 4)])
+			.build_and_store());
+		assert!(!ChainlinkFeed::feed(feed_id)
+			.unwrap()
+			.is_current_data_fresh(max_age));
+
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			1,
+			42
+		));
+		let data = ChainlinkFeed::feed(feed_id).unwrap().latest_data();
+		assert!(crate::is_fresh(&data, 1, 0));
+		assert!(!crate::is_fresh(&data, 2, 0));
+
+		System::set_block_number(1 + max_age);
+		assert!(ChainlinkFeed::feed(feed_id)
+			.unwrap()
+			.is_current_data_fresh(max_age));
+		System::set_block_number(1 + max_age + 1);
+		assert!(!ChainlinkFeed::feed(feed_id)
+			.unwrap()
+			.is_current_data_fresh(max_age));
+	});
+}
+
+#[test]
+fn round_data_age_should_work() {
+	let data = RoundData {
+		round_id: 1,
+		started_at: 3u64,
+		answer: 42u64,
+		updated_at: 5,
+		answered_in_round: 1,
+	};
+	assert_eq!(data.age_in_blocks(5), 0);
+	assert_eq!(data.age_in_blocks(8), 3);
+	// blocks before the update saturate at zero
+	assert_eq!(data.age_in_blocks(2), 0);
+
+	assert!(data.is_fresh(8, 3));
+	assert!(!data.is_fresh(9, 3));
+	assert!(data.is_fresh(5, 0));
+	assert!(data.is_fresh(2, 0));
+}
+
+#[test]
+fn submission_history_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let oracle = 2;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.restart_delay(0)
+			.oracles(vec![(oracle, 4), (3, 4)])
+			.build_and_store());
+		assert_eq!(ChainlinkFeed::submission_history(feed_id, &oracle), vec![]);
+
+		for (round, submission) in (1..=4).zip(vec![10, 11, 12, 13]) {
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(oracle),
+				feed_id,
+				round,
+				submission
+			));
+		}
+		// newest first and limited to `HistoryDepth`
+		assert_eq!(
+			ChainlinkFeed::submission_history(feed_id, &oracle),
+			vec![13, 12, 11]
+		);
+		assert_eq!(ChainlinkFeed::submission_history(feed_id, &3), vec![]);
+	});
+}
+
+#[test]
+fn feed_config_rpc_conversion_should_work() {
+	use crate::rpc::{FeedConfigRpc, RpcConversionError};
+	use sp_std::convert::TryFrom;
+
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new()
+			.description(b"LINK/USD".to_vec())
+			.max_debt(42)
+			.build_and_store());
+		let config = ChainlinkFeed::feed_config(feed_id).unwrap();
+		let rpc = FeedConfigRpc::try_from(config.clone()).unwrap();
+		assert_eq!(rpc.owner, "0x0100000000000000");
+		assert_eq!(rpc.pending_owner, None);
+		assert_eq!(rpc.description, "LINK/USD");
+		assert_eq!(rpc.submission_value_bounds, (1, 1_000));
+		assert_eq!(rpc.payment, 20);
+		assert_eq!(rpc.max_debt, Some(42));
+		assert!(rpc.tags.is_empty());
+
+		let mut invalid = config.clone();
+		invalid.description = vec![0xff, 0xfe].try_into().unwrap();
+		assert_eq!(
+			FeedConfigRpc::try_from(invalid),
+			Err(RpcConversionError::InvalidDescription)
+		);
+		let mut invalid = config;
+		invalid.tags = vec![vec![0xff].try_into().unwrap()].try_into().unwrap();
+		assert_eq!(
+			FeedConfigRpc::try_from(invalid),
+			Err(RpcConversionError::InvalidTag)
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_should_work() {
+	use crate::migrations::v1::{
+		MigrateToV1, OldFeedConfigOf, OldOracleMetaOf, OldOracleStatusOf, OldRoundDetailsOf,
+	};
+	use codec::Encode;
+	use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		let (feed_id, round_id) = (3, 2);
+		let (enabled, disabled, admin) = (2, 3, 4);
+		let old_config = OldFeedConfigOf::<Test> {
+			owner: 1,
+			submission_value_bounds: (1, 100),
+			submission_count_bounds: (1, 3),
+			payment: 20,
+			description: b"desc".to_vec(),
+			oracle_count: 1,
+			pruning_window: RoundId::MAX,
+			next_round_to_prune: 1,
+			..Default::default()
+		};
+		let old_details = OldRoundDetailsOf::<Test> {
+			submissions: vec![42],
+			submission_count_bounds: (1, 3),
+			payment: 20,
+			timeout: 10,
+		};
+		let old_meta = OldOracleMetaOf::<Test> {
+			withdrawable: 30,
+			admin,
+			pending_admin: None,
+		};
+		sp_io::storage::set(
+			&Feeds::<Test>::hashed_key_for(feed_id),
+			&old_config.encode(),
+		);
+		sp_io::storage::set(
+			&Details::<Test>::hashed_key_for(feed_id, round_id),
+			&old_details.encode(),
+		);
+		for (oracle, ending_round) in vec![(enabled, None), (disabled, Some(1))] {
+			let old_status = OldOracleStatusOf::<Test> {
+				starting_round: 1,
+				ending_round,
+				last_reported_round: Some(1),
+				latest_submission: Some(42),
+				..Default::default()
+			};
+			sp_io::storage::set(&Oracles::<Test>::hashed_key_for(oracle), &old_meta.encode());
+			sp_io::storage::set(
+				&OracleStatuses::<Test>::hashed_key_for(feed_id, oracle),
+				&old_status.encode(),
+			);
+		}
+		StorageVersion::new(0).put::<ChainlinkFeed>();
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<ChainlinkFeed>(), 1);
+		let config = ChainlinkFeed::feed_config(feed_id).expect("config should decode");
+		assert_eq!(config.id, feed_id);
+		assert_eq!(config.owner, old_config.owner);
+		assert_eq!(config.description.to_vec(), old_config.description);
+		assert_eq!(config.pruning_window, old_config.pruning_window);
+		assert_eq!(config.treasury_percent, 0);
+		assert!(!config.deprecated);
+		assert_eq!(config.version, 1);
+		assert_eq!(
+			ChainlinkFeed::feed_id_for_description(b"desc"),
+			Some(feed_id)
+		);
+
+		let details =
+			ChainlinkFeed::round_details(feed_id, round_id).expect("details should decode");
+		assert_eq!(details.submissions.to_vec(), old_details.submissions);
+		assert_eq!(details.started_by, ChainlinkFeed::fund_account_id());
+		assert_eq!(details.deadline, None);
+
+		let meta = ChainlinkFeed::oracle(enabled).expect("meta should decode");
+		assert_eq!(meta.withdrawable, old_meta.withdrawable);
+		assert_eq!(meta.total_earned, old_meta.withdrawable);
+		assert_eq!(meta.total_withdrawn, 0);
+
+		let status = ChainlinkFeed::oracle_status(feed_id, enabled).expect("status should decode");
+		assert_eq!(status.last_reported_round, Some(1));
+		assert_eq!(status.last_reported_block, None);
+		assert_eq!(status.submission_count, 0);
+		assert_eq!(ChainlinkFeed::feeds_for_oracle(&enabled), vec![feed_id]);
+		assert_eq!(ChainlinkFeed::feeds_for_oracle(&disabled), vec![]);
+
+		// running it again is a noop
+		let config_before = config;
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(ChainlinkFeed::feed_config(feed_id), Some(config_before));
+	});
+}
+
+#[test]
+fn runtime_upgrade_from_unversioned_storage_should_work() {
+	use crate::migrations::v1::{
+		OldFeedConfigOf, OldOracleMetaOf, OldOracleStatusOf, OldRoundDetailsOf,
+	};
+	use codec::Encode;
+	use frame_support::traits::{Hooks, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		let (feed_id, round_id) = (0, 1);
+		let (reported, pending, admin) = (2, 3, 4);
+		// a feed with an open round as stored by the unversioned pallet
+		FeedCounter::<Test>::put(1);
+		let old_config = OldFeedConfigOf::<Test> {
+			owner: 1,
+			submission_value_bounds: (1, 100),
+			submission_count_bounds: (2, 2),
+			payment: 20,
+			timeout: 10,
+			decimals: 5,
+			description: b"desc".to_vec(),
+			restart_delay: 1,
+			reporting_round: round_id,
+			oracle_count: 2,
+			pruning_window: RoundId::MAX,
+			next_round_to_prune: 1,
+			..Default::default()
+		};
+		sp_io::storage::set(
+			&Feeds::<Test>::hashed_key_for(feed_id),
+			&old_config.encode(),
+		);
+		// the layout of rounds did not change
+		Rounds::<Test>::insert(feed_id, round_id, Round::new(1));
+		let old_details = OldRoundDetailsOf::<Test> {
+			submissions: vec![42],
+			submission_count_bounds: (2, 2),
+			payment: 20,
+			timeout: 10,
+		};
+		sp_io::storage::set(
+			&Details::<Test>::hashed_key_for(feed_id, round_id),
+			&old_details.encode(),
+		);
+		let old_statuses = vec![
+			(
+				reported,
+				20,
+				OldOracleStatusOf::<Test> {
+					starting_round: 1,
+					last_reported_round: Some(round_id),
+					last_started_round: Some(round_id),
+					latest_submission: Some(42),
+					..Default::default()
+				},
+			),
+			(
+				pending,
+				0,
+				OldOracleStatusOf::<Test> {
+					starting_round: 1,
+					..Default::default()
+				},
+			),
+		];
+		for (oracle, withdrawable, old_status) in old_statuses {
+			let old_meta = OldOracleMetaOf::<Test> {
+				withdrawable,
+				admin,
+				pending_admin: None,
+			};
+			sp_io::storage::set(&Oracles::<Test>::hashed_key_for(oracle), &old_meta.encode());
+			sp_io::storage::set(
+				&OracleStatuses::<Test>::hashed_key_for(feed_id, oracle),
+				&old_status.encode(),
+			);
+		}
+		StorageVersion::new(0).put::<ChainlinkFeed>();
+
+		<ChainlinkFeed as Hooks<BlockNumber>>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<ChainlinkFeed>(), 1);
+		assert_ok!(ChainlinkFeed::check_storage_consistency());
+		assert_eq!(
+			ChainlinkFeed::feed_id_for_description(b"desc"),
+			Some(feed_id)
+		);
+
+		// the open round can be completed after the upgrade
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(reported), feed_id, round_id, 42),
+			Error::<Test>::ReportingOrder
+		);
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(pending),
+			feed_id,
+			round_id,
+			44
+		));
+		let round = ChainlinkFeed::round(feed_id, round_id).expect("round should exist");
+		assert_eq!(round.answer, Some(43));
+		let config = ChainlinkFeed::feed_config(feed_id).expect("feed should exist");
+		assert_eq!(config.first_valid_round, Some(round_id));
+		assert_eq!(ChainlinkFeed::oracle(pending).unwrap().withdrawable, 20);
+		assert_ok!(ChainlinkFeed::check_storage_consistency());
+	});
+}
+
+#[test]
+fn storage_consistency_check_should_work() {
+	TestExtBuilder::default()
+		.with_feed(FeedBuilder::new())
+		.build()
+		.execute_with(|| {
+			let feed_id = 0;
+			let oracle = 2;
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(oracle),
+				feed_id,
+				1,
+				42
+			));
+			assert_ok!(ChainlinkFeed::check_storage_consistency());
+
+			// wrong oracle count
+			Feeds::<Test>::mutate(feed_id, |config| {
+				config.as_mut().unwrap().oracle_count += 1;
+			});
+			assert!(ChainlinkFeed::check_storage_consistency().is_err());
+			Feeds::<Test>::mutate(feed_id, |config| {
+				config.as_mut().unwrap().oracle_count -= 1;
+			});
+			assert_ok!(ChainlinkFeed::check_storage_consistency());
+
+			// missing oracle meta data
+			let meta = Oracles::<Test>::take(oracle).unwrap();
+			assert!(ChainlinkFeed::check_storage_consistency().is_err());
+			Oracles::<Test>::insert(oracle, meta);
+
+			// round details beyond the reporting round
+			let details = ChainlinkFeed::round_details(feed_id, 1).unwrap();
+			Details::<Test>::insert(feed_id, 5, details);
+			assert!(ChainlinkFeed::check_storage_consistency().is_err());
+		});
+}
+
+#[test]
+fn failing_extrinsics_should_not_leave_partial_writes() {
+	new_test_ext().execute_with(|| {
+		// oracle 2 with admin 4
+		assert_ok!(FeedBuilder::new().build_and_store());
+		let counter = ChainlinkFeed::feed_counter();
+
+		// the new oracle is added before the admin mismatch of oracle 2 is detected
+		let new_oracle = 10;
+		assert_noop!(
+			FeedBuilder::new()
+				.oracles(vec![(new_oracle, 11), (2, 5)])
+				.min_submissions(1)
+				.build_and_store(),
+			Error::<Test>::OwnerCannotChangeAdmin
+		);
+		assert_eq!(ChainlinkFeed::feed_counter(), counter);
+		assert_eq!(ChainlinkFeed::oracle(new_oracle), None);
+		assert_eq!(ChainlinkFeed::feed_config(counter), None);
+	});
+}
+
+#[test]
+fn feeds_by_description_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(ChainlinkFeed::feed_id_for_description(b"LINK/USD"), None);
+		assert_ok!(FeedBuilder::new()
+			.description(b"LINK/USD".to_vec())
+			.build_and_store());
+		assert_ok!(FeedBuilder::new()
+			.description(b"DOT/USD".to_vec())
+			.build_and_store());
+		assert_eq!(ChainlinkFeed::feed_id_for_description(b"LINK/USD"), Some(0));
+		assert_eq!(ChainlinkFeed::feed_id_for_description(b"DOT/USD"), Some(1));
+
+		let duplicates = || -> Vec<_> {
+			System::events()
+				.into_iter()
+				.filter_map(|r| match r.event {
+					mock::Event::ChainlinkFeed(crate::Event::DuplicateDescription(
+						previous,
+						feed,
+						_,
+					)) => Some((previous, feed)),
+					_ => None,
+				})
+				.collect()
+		};
+		assert!(duplicates().is_empty());
+		// the most recent feed wins
+		assert_ok!(FeedBuilder::new()
+			.description(b"LINK/USD".to_vec())
+			.build_and_store());
+		assert_eq!(ChainlinkFeed::feed_id_for_description(b"LINK/USD"), Some(2));
+		assert_eq!(duplicates(), vec![(0, 2)]);
+
+		// changing the description updates the index
+		tx_assert_ok!(ChainlinkFeed::update_feed_config(
+			Origin::signed(1),
+			1,
+			FeedConfigUpdates {
+				description: Some(b"KSM/USD".to_vec()),
+				..Default::default()
+			}
+		));
+		assert_eq!(ChainlinkFeed::feed_id_for_description(b"DOT/USD"), None);
+		assert_eq!(ChainlinkFeed::feed_id_for_description(b"KSM/USD"), Some(1));
+	});
+}
+
+#[test]
+fn fund_account_should_work() {
+	new_test_ext().execute_with(|| {
+		let fund: AccountId = FeedPalletId::get().into_account();
+		assert_eq!(ChainlinkFeed::fund_account_id(), fund);
+		assert_eq!(ChainlinkFeed::fund_account(), fund);
+	});
+}
+
+#[test]
+fn submissions_are_bounded_by_the_oracle_limit() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let limit = OracleLimit::get();
+		let oracles: Vec<(AccountId, AccountId)> =
+			(0..limit as u64).map(|i| (100 + i, 200)).collect();
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(limit)
+			.oracles(oracles.clone())
+			.build_and_store());
+
+		for (oracle, _) in oracles.iter().take(limit as usize - 1) {
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(*oracle),
+				feed_id,
+				1,
+				42
+			));
+		}
+		let details = ChainlinkFeed::round_details(feed_id, 1).unwrap();
+		assert_eq!(details.submissions.len() as u32, limit - 1);
+
+		// the last submission fills the bounded vec up to the limit
+		let (last_oracle, _) = oracles[limit as usize - 1];
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(last_oracle),
+			feed_id,
+			1,
+			42
+		));
+		assert_eq!(ChainlinkFeed::round(feed_id, 1).unwrap().answer, Some(42));
+		// details are removed once the maximum is reached
+		assert_eq!(ChainlinkFeed::round_details(feed_id, 1), None);
+	});
+}
+
+#[test]
+fn timeout_overflow_should_not_time_out() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let round_id = 1;
+		let timeout = 2;
+		assert_ok!(FeedBuilder::new().timeout(timeout).build_and_store());
+		Rounds::<Test>::insert(feed_id, round_id, Round::new(BlockNumber::MAX - 1));
+		Details::<Test>::insert(
+			feed_id,
+			round_id,
+			RoundDetails {
+				submissions: Default::default(),
+				submission_count_bounds: (2, 3),
+				payment: 20,
+				timeout,
+				started_by: 2,
+				deadline: None,
+			},
+		);
+		System::set_block_number(BlockNumber::MAX);
+
+		let feed = Feed::<Test>::read_only_from(feed_id).unwrap();
+		assert!(!feed.is_timed_out(round_id));
+	});
+}
+
+#[test]
+fn prune_single_round_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		let oracle = 2;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.min_submissions(1)
+			.restart_delay(0)
+			.oracles(vec![(oracle, 4), (3, 4)])
+			.build_and_store());
+		for round in 1..=5 {
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(oracle),
+				feed_id,
+				round,
+				42
+			));
+		}
+		// shrink the window without pruning
+		Feeds::<Test>::mutate(feed_id, |config| {
+			config.as_mut().unwrap().pruning_window = 2;
+		});
+
+		assert_noop!(
+			ChainlinkFeed::prune_single_round(Origin::signed(owner), feed_id, 0),
+			Error::<Test>::CannotPruneRoundZero
+		);
+		assert_noop!(
+			ChainlinkFeed::prune_single_round(Origin::signed(123), feed_id, 3),
+			Error::<Test>::NotFeedOwner
+		);
+		// within the window
+		assert_noop!(
+			ChainlinkFeed::prune_single_round(Origin::signed(owner), feed_id, 4),
+			Error::<Test>::NothingToPrune
+		);
+
+		assert_ok!(ChainlinkFeed::prune_single_round(
+			Origin::signed(owner),
+			feed_id,
+			3
+		));
+		assert_eq!(ChainlinkFeed::round(feed_id, 3), None);
+		let config = ChainlinkFeed::feed_config(feed_id).unwrap();
+		assert_eq!(config.next_round_to_prune, 1);
+		assert_noop!(
+			ChainlinkFeed::prune_single_round(Origin::signed(owner), feed_id, 3),
+			Error::<Test>::NothingToPrune
+		);
+
+		// pruning the oldest round moves the first valid round
+		assert_ok!(ChainlinkFeed::prune_single_round(
+			Origin::signed(owner),
+			feed_id,
+			1
+		));
+		assert_eq!(ChainlinkFeed::round(feed_id, 1), None);
+		let config = ChainlinkFeed::feed_config(feed_id).unwrap();
+		assert_eq!(config.next_round_to_prune, 2);
+		assert_eq!(config.first_valid_round, Some(2));
+		assert!(ChainlinkFeed::round(feed_id, 2).is_some());
+	});
+}
+
+#[test]
+fn boxed_feeds_should_work() {
+	new_test_ext().execute_with(|| {
+		let oracle = 2;
+		for _ in 0..2 {
+			assert_ok!(FeedBuilder::new()
+				.min_submissions(1)
+				.oracles(vec![(oracle, 4), (3, 4)])
+				.build_and_store());
+		}
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(oracle), 1, 1, 42));
+
+		let feeds: Vec<BoxedFeed<Test>> = (0..2)
+			.map(|id| Feed::<Test>::read_only_from(id).unwrap().into_boxed())
+			.collect();
+		let answers: Vec<_> = feeds.iter().map(|feed| feed.latest_answer()).collect();
+		assert_eq!(answers, vec![None, Some(42)]);
+	});
+}
+
+#[test]
+fn set_round_payment_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		let oracle = 2;
+		let payment = 50;
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+
+		assert_noop!(
+			ChainlinkFeed::set_round_payment(Origin::signed(123), feed_id, 1, payment),
+			Error::<Test>::NotFeedOwner
+		);
+		assert_noop!(
+			ChainlinkFeed::set_round_payment(Origin::signed(owner), feed_id, 0, payment),
+			Error::<Test>::InvalidRound
+		);
+
+		System::set_block_number(1);
+		assert_ok!(ChainlinkFeed::set_round_payment(
+			Origin::signed(owner),
+			feed_id,
+			1,
+			payment
+		));
+		let set_event =
+			mock::Event::ChainlinkFeed(crate::Event::RoundPaymentSet(feed_id, 1, payment));
+		assert!(System::events().iter().any(|e| e.event == set_event));
+		assert_eq!(
+			ChainlinkFeed::round_payment_override(feed_id, 1),
+			Some(payment)
+		);
+
+		// the override is used once the round starts
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			1,
+			42
+		));
+		assert_eq!(ChainlinkFeed::round_payment_override(feed_id, 1), None);
+		let details = ChainlinkFeed::round_details(feed_id, 1).unwrap();
+		assert_eq!(details.payment, payment);
+		assert_eq!(ChainlinkFeed::oracle(oracle).unwrap().withdrawable, payment);
+
+		assert_noop!(
+			ChainlinkFeed::set_round_payment(Origin::signed(owner), feed_id, 1, 10),
+			Error::<Test>::RoundHasSubmissions
+		);
+	});
+}
+
+#[test]
+fn withdraw_payment_all_should_work() {
+	new_test_ext().execute_with(|| {
+		let admin = 4;
+		let recipient = 5;
+		let payment = 20;
+		assert_ok!(FeedBuilder::new()
+			.payment(payment)
+			.min_submissions(1)
+			.oracles(vec![(2, admin), (3, admin), (6, 7)])
+			.build_and_store());
+		for oracle in vec![2, 3, 6] {
+			assert_ok!(ChainlinkFeed::submit(Origin::signed(oracle), 0, 1, 42));
+		}
+
+		assert_noop!(
+			ChainlinkFeed::withdraw_payment_all(Origin::signed(admin), vec![2, 123], recipient),
+			Error::<Test>::OracleNotFound
+		);
+		// all or nothing
+		assert_noop!(
+			ChainlinkFeed::withdraw_payment_all(Origin::signed(admin), vec![2, 3, 6], recipient),
+			Error::<Test>::NotAdmin
+		);
+		assert_eq!(ChainlinkFeed::oracle(2).unwrap().withdrawable, payment);
+		assert_eq!(ChainlinkFeed::oracle(2).unwrap().total_earned, payment);
+
+		// duplicates are only paid once
+		assert_ok!(ChainlinkFeed::withdraw_payment_all(
+			Origin::signed(admin),
+			vec![2, 3, 2],
+			recipient
+		));
+		assert_eq!(Balances::free_balance(&recipient), 2 * payment);
+		assert_eq!(ChainlinkFeed::oracle(2).unwrap().withdrawable, 0);
+		assert_eq!(ChainlinkFeed::oracle(3).unwrap().withdrawable, 0);
+		assert_eq!(ChainlinkFeed::oracle(6).unwrap().withdrawable, payment);
+		let meta = ChainlinkFeed::oracle(2).unwrap();
+		assert_eq!(meta.total_earned, payment);
+		assert_eq!(meta.total_withdrawn, payment);
+		assert_eq!(ChainlinkFeed::oracle(6).unwrap().total_withdrawn, 0);
+	});
+}
+
+#[test]
+fn oracle_round_start_respects_restart_delay_boundary() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let oracle = 2;
+		let other = 3;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.restart_delay(1)
+			.build_and_store());
+
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			1,
+			42
+		));
+		// round 2 is exactly `restart_delay` rounds after round 1
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(oracle), feed_id, 2, 42),
+			Error::<Test>::RoundNotInitialized
+		);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(other), feed_id, 2, 42));
+		// one round past the delay
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle),
+			feed_id,
+			3,
+			42
+		));
+		let status = ChainlinkFeed::oracle_status(feed_id, oracle).unwrap();
+		assert_eq!(status.last_started_round, Some(3));
+	});
+}
+
+#[test]
+fn mock_feed_oracle_should_work() {
+	use crate::testing::MockFeedOracle;
+	type Oracle = MockFeedOracle<Test>;
+
+	new_test_ext().execute_with(|| {
+		Oracle::reset();
+		assert!(Oracle::feed(0).is_none());
+
+		System::set_block_number(5);
+		Oracle::set_answer(0, 2, 42);
+		Oracle::set_answer(0, 1, 21);
+		Oracle::set_decimals(0, 8);
+		assert!(Oracle::is_valid_feed(0));
+		assert!(!Oracle::is_valid_feed(1));
+		assert_eq!(Oracle::all_feed_ids().collect::<Vec<_>>(), vec![0]);
+
+		let feed = Oracle::feed(0).unwrap();
+		assert_eq!(feed.first_valid_round(), Some(1));
+		assert_eq!(feed.latest_round(), 2);
+		assert_eq!(feed.latest_answer(), Some(42));
+		assert_eq!(feed.decimals(), 8);
+		let data = feed.data_at(1).unwrap();
+		assert_eq!((data.answer, data.updated_at), (21, 5));
+
+		let mut feed = Oracle::feed_mut(0).unwrap();
+		Oracle::fail_next_new_round();
+		assert!(feed.request_new_round(1).is_err());
+		assert_ok!(feed.request_new_round(1));
+		assert_eq!(Oracle::requested_rounds(0), 1);
+		assert_ne!(Oracle::system_account(), AccountId::default());
+	});
+}
+
+#[test]
+fn feed_oracle_set_answer_should_work() {
+	use crate::testing::MockFeedOracle;
+
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_noop!(
+			<ChainlinkFeed as FeedOracle<Test>>::set_answer(feed_id, 1, 42),
+			Error::<Test>::FeedNotFound
+		);
+		assert_ok!(FeedBuilder::new().build_and_store());
+		assert_noop!(
+			<ChainlinkFeed as FeedOracle<Test>>::set_answer(feed_id, 0, 42),
+			Error::<Test>::InvalidRound
+		);
+
+		System::set_block_number(3);
+		assert_ok!(<ChainlinkFeed as FeedOracle<Test>>::set_answer(
+			feed_id, 2, 42
+		));
+		let feed = ChainlinkFeed::feed(feed_id).unwrap();
+		assert_eq!(feed.latest_round(), 2);
+		assert_eq!(feed.first_valid_round(), Some(2));
+		assert_eq!(feed.latest_answer(), Some(42));
+		assert_eq!(feed.latest_data().updated_at, 3);
+		// an older round does not move the latest round back
+		assert_ok!(<ChainlinkFeed as FeedOracle<Test>>::set_answer(
+			feed_id, 1, 21
+		));
+		let feed = ChainlinkFeed::feed(feed_id).unwrap();
+		assert_eq!(feed.latest_round(), 2);
+		assert_eq!(feed.data_at(1).unwrap().answer, 21);
+
+		MockFeedOracle::<Test>::reset();
+		assert_ok!(<MockFeedOracle<Test> as FeedOracle<Test>>::set_answer(
+			0, 1, 7
+		));
+		assert_eq!(
+			MockFeedOracle::<Test>::feed(0).unwrap().latest_answer(),
+			Some(7)
+		);
+	});
+}
+
+#[test]
+fn aggregator_v3_latest_round_data_should_work() {
+	use crate::traits::ChainlinkAggregatorV3;
+
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		NowMillis::set(1_000_000_000);
+		assert_eq!(ChainlinkFeed::latest_round_data(feed_id), None);
+		assert_ok!(FeedBuilder::new().build_and_store());
+		// the seed round is not served
+		assert_eq!(ChainlinkFeed::latest_round_data(feed_id), None);
+
+		System::set_block_number(2);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+		System::set_block_number(3);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 42));
+
+		// block 3 is the current block and was produced one block after block 2
+		assert_eq!(
+			ChainlinkFeed::latest_round_data(feed_id),
+			Some((1, 42, 999_994, 1_000_000, 1))
+		);
+		System::set_block_number(5);
+		NowMillis::set(1_000_012_000);
+		assert_eq!(
+			ChainlinkFeed::latest_round_data(feed_id),
+			Some((1, 42, 999_994, 1_000_000, 1))
+		);
+		assert_eq!(ChainlinkFeed::block_to_unix_seconds(6), 1_000_012);
+	});
+}
+
+#[test]
+fn data_at_should_report_errors() {
+	use frame_support::sp_runtime::DispatchError;
+
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new().restart_delay(0).build_and_store());
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 42));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 2, 42));
+
+		let feed = ChainlinkFeed::feed(feed_id).unwrap();
+		assert_ok!(feed.data_at(1));
+		// the round was started but has no answer, yet
+		assert_eq!(
+			feed.data_at(2),
+			Err(DispatchError::Other("RoundConversionError::MissingField"))
+		);
+		assert_eq!(feed.data_at(3), Err(Error::<Test>::RoundNotFound.into()));
+	});
+}
+
+#[test]
+fn timed_out_rounds_emit_events() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let (oracle_a, oracle_b, oracle_c) = (2, 3, 4);
+		assert_ok!(FeedBuilder::new()
+			.timeout(1)
+			.min_submissions(2)
+			.restart_delay(0)
+			.build_and_store());
+		let has_event = |event| {
+			System::events()
+				.iter()
+				.any(|e| e.event == mock::Event::ChainlinkFeed(event))
+		};
+
+		System::set_block_number(1);
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle_a),
+			feed_id,
+			1,
+			42
+		));
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle_b),
+			feed_id,
+			1,
+			42
+		));
+
+		// round 2 only receives a single submission
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle_a),
+			feed_id,
+			2,
+			42
+		));
+		System::set_block_number(3);
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle_b),
+			feed_id,
+			3,
+			42
+		));
+		assert!(has_event(crate::Event::RoundAborted(feed_id, 2, 1)));
+		assert!(!has_event(crate::Event::RoundTimedOut(feed_id, 2)));
+
+		// round 4 is started via request and never receives a submission
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle_c),
+			feed_id,
+			3,
+			42
+		));
+		{
+			tx_assert_ok!(ChainlinkFeed::feed_mut(feed_id)
+				.unwrap()
+				.request_new_round(ChainlinkFeed::system_account()));
+		}
+		System::set_block_number(5);
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle_a),
+			feed_id,
+			5,
+			42
+		));
+		assert!(has_event(crate::Event::RoundTimedOut(feed_id, 4)));
+	});
+}
+
+#[test]
+fn paginated_rounds_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.restart_delay(0)
+			.pruning_window(2)
+			.build_and_store());
+		for round in 1..=4 {
+			assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, round, 42));
+		}
+
+		// rounds 1 and 2 were pruned
+		let ids = |start, count| -> Vec<RoundId> {
+			ChainlinkFeed::paginated_rounds(feed_id, start, count)
+				.into_iter()
+				.map(|(id, _)| id)
+				.collect()
+		};
+		assert_eq!(ids(0, 10), vec![0, 3, 4]);
+		assert_eq!(ids(3, 1), vec![3]);
+		assert_eq!(ids(5, 10), Vec::<RoundId>::new());
+		assert_eq!(ids(RoundId::MAX, 10), Vec::<RoundId>::new());
+		// the page size is clamped instead of reading `count` rounds
+		assert_eq!(ids(0, u32::MAX), vec![0, 3, 4]);
+		let (_, round) = ChainlinkFeed::paginated_rounds(feed_id, 4, 1).remove(0);
+		assert_eq!(round.answer, Some(42));
+	});
+}
+
+#[test]
+fn data_at_should_ignore_the_seed_round() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new().min_submissions(1).build_and_store());
+		assert!(ChainlinkFeed::round(feed_id, 0).is_some());
+
+		let feed = ChainlinkFeed::feed(feed_id).unwrap();
+		assert_eq!(feed.data_at(0), Err(Error::<Test>::RoundNotFound.into()));
+		// the seed data is still served as the latest data
+		assert_eq!(feed.latest_data().answer, 0);
+
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+		let feed = ChainlinkFeed::feed(feed_id).unwrap();
+		assert_eq!(feed.data_at(0), Err(Error::<Test>::RoundNotFound.into()));
+		assert_eq!(feed.data_at(1).map(|data| data.answer), Ok(42));
+	});
+}
+
+#[test]
+fn requester_delay_minimum_should_be_enforced() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		let requester = 22;
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		// set before the minimum is raised
+		assert_ok!(ChainlinkFeed::set_requester(
+			Origin::signed(owner),
+			feed_id,
+			requester,
+			0
+		));
+
+		MinRequesterDelay::set(&2);
+		assert_noop!(
+			ChainlinkFeed::set_requester(Origin::signed(owner), feed_id, requester, 1),
+			Error::<Test>::RequesterDelayTooSmall
+		);
+		assert_noop!(
+			ChainlinkFeed::request_new_round(Origin::signed(requester), feed_id),
+			Error::<Test>::RequesterDelayTooSmall
+		);
+		assert_ok!(ChainlinkFeed::set_requester(
+			Origin::signed(owner),
+			feed_id,
+			requester,
+			2
+		));
+		assert_ok!(ChainlinkFeed::request_new_round(
+			Origin::signed(requester),
+			feed_id
+		));
+		MinRequesterDelay::set(&0);
+	});
+}
+
+#[test]
+fn snapshot_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_eq!(ChainlinkFeed::snapshot(feed_id), None);
+		assert_ok!(FeedBuilder::new().build_and_store());
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+
+		let snapshot = ChainlinkFeed::snapshot(feed_id).expect("feed should exist");
+		assert_eq!(
+			snapshot.config,
+			ChainlinkFeed::feed_config(feed_id).unwrap()
+		);
+		assert_eq!(
+			snapshot.latest_round,
+			ChainlinkFeed::round(feed_id, 0).unwrap()
+		);
+		assert_eq!(
+			snapshot.latest_details,
+			ChainlinkFeed::round_details(feed_id, 1)
+		);
+		assert_eq!(
+			snapshot.latest_details.unwrap().submissions.into_inner(),
+			vec![42]
+		);
+		assert_eq!(snapshot.active_oracle_count, 3);
+
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 42));
+		let snapshot = ChainlinkFeed::snapshot(feed_id).unwrap();
+		assert_eq!(snapshot.config.latest_round, 1);
+		assert_eq!(snapshot.latest_round.answer, Some(42));
+	});
+}
+
+#[test]
+fn transfer_admin_batch_should_work() {
+	new_test_ext().execute_with(|| {
+		let admin = 4;
+		let other_admin = 5;
+		assert_ok!(FeedBuilder::new()
+			.oracles(vec![(2, admin), (3, admin), (6, other_admin)])
+			.build_and_store());
+
+		let new_admin = 42;
+		assert_noop!(
+			ChainlinkFeed::transfer_admin_batch(
+				Origin::signed(admin),
+				vec![(2, new_admin), (123, new_admin)]
+			),
+			Error::<Test>::OracleNotFound
+		);
+		// all or nothing
+		assert_noop!(
+			ChainlinkFeed::transfer_admin_batch(
+				Origin::signed(admin),
+				vec![(2, new_admin), (6, new_admin)]
+			),
+			Error::<Test>::NotAdmin
+		);
+		assert_eq!(ChainlinkFeed::oracle(2).unwrap().pending_admin, None);
+
+		System::set_block_number(1);
+		assert_ok!(ChainlinkFeed::transfer_admin_batch(
+			Origin::signed(admin),
+			vec![(2, new_admin), (3, new_admin)]
+		));
+		for oracle in vec![2, 3] {
+			let oracle_meta = ChainlinkFeed::oracle(oracle).expect("oracle should be present");
+			assert_eq!(oracle_meta.pending_admin, Some(new_admin));
+			assert!(System::events().iter().any(|r| r.event
+				== mock::Event::ChainlinkFeed(crate::Event::OracleAdminUpdateRequested(
+					oracle, admin, new_admin
+				))));
+		}
+		assert_eq!(ChainlinkFeed::oracle(6).unwrap().pending_admin, None);
+	});
+}
+
+#[test]
+fn oracle_statuses_for_feed_should_work() {
+	TestExtBuilder::default()
+		.with_feed(FeedBuilder::new())
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeedBuilder::new()
+				.oracles(vec![(5, 4), (6, 4)])
+				.min_submissions(1)
+				.build_and_store());
+
+			let mut oracles: Vec<AccountId> = ChainlinkFeed::oracle_statuses_for_feed(0)
+				.map(|(oracle, _)| oracle)
+				.collect();
+			oracles.sort();
+			assert_eq!(oracles, vec![2, 3, 4]);
+			let statuses: Vec<_> = ChainlinkFeed::oracle_statuses_for_feed(1).collect();
+			assert_eq!(statuses.len(), 2);
+			for (oracle, status) in statuses {
+				assert_eq!(Some(status), ChainlinkFeed::oracle_status(1, oracle));
+			}
+			assert_eq!(ChainlinkFeed::oracle_statuses_for_feed(2).count(), 0);
+		});
+}
+
+#[test]
+fn oracle_enabled_since_should_be_recorded() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		System::set_block_number(3);
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		let enabled_since = |oracle| {
+			ChainlinkFeed::oracle_status(feed_id, oracle)
+				.expect("status should be there")
+				.enabled_since
+		};
+		assert_eq!(enabled_since(2), 3);
+
+		System::set_block_number(5);
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![2],
+			vec![(5, 4)],
+		));
+		assert_eq!(enabled_since(5), 5);
+		// disabling keeps the block
+		assert_eq!(enabled_since(2), 3);
+
+		// re-enabling resets it
+		System::set_block_number(8);
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![],
+			vec![(2, 4)],
+		));
+		assert_eq!(enabled_since(2), 8);
+		assert_eq!(enabled_since(3), 3);
+	});
+}
+
+#[test]
+fn oracle_feed_earnings_should_accumulate() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let payment = 20;
+		assert_ok!(FeedBuilder::new()
+			.payment(payment)
+			.min_submissions(1)
+			.restart_delay(0)
+			.build_and_store());
+		assert_eq!(ChainlinkFeed::oracle_feed_earnings(feed_id, &2), Some(0));
+
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 2, 43));
+		assert_eq!(
+			ChainlinkFeed::oracle_feed_earnings(feed_id, &2),
+			Some(2 * payment)
+		);
+		// oracles that did not submit earned nothing
+		assert_eq!(ChainlinkFeed::oracle_feed_earnings(feed_id, &3), Some(0));
+		// unknown oracles have no status
+		assert_eq!(ChainlinkFeed::oracle_feed_earnings(feed_id, &42), None);
+	});
+}
+
+#[test]
+fn display_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new()
+			.description(b"LINK/USD".to_vec())
+			.max_debt(42)
+			.build_and_store());
+		let config = ChainlinkFeed::feed_config(feed_id).unwrap();
+		let printed = config.to_string();
+		let lines: Vec<&str> = printed.lines().collect();
+		assert_eq!(lines.len(), 27);
+		assert_eq!(lines[0], "id: 0");
+		assert_eq!(lines[1], "owner: 0x0100000000000000");
+		assert_eq!(lines[2], "pending_owner: none");
+		assert!(lines.contains(&"description: LINK/USD"));
+		assert!(lines.contains(&"submission_value_bounds: (1, 1000)"));
+		assert!(lines.contains(&"max_debt: 42"));
+
+		let data = RoundData {
+			round_id: 3,
+			started_at: 1u64,
+			answer: 42u64,
+			updated_at: 2,
+			answered_in_round: 3,
+		};
+		assert_eq!(
+			data.to_string(),
+			"round_id: 3\nstarted_at: 1\nanswer: 42\nupdated_at: 2\nanswered_in_round: 3"
+		);
+	});
+}
+
+#[test]
+fn data_range_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.restart_delay(0)
+			.pruning_window(3)
+			.build_and_store());
+		for round in 1..=5 {
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(2),
+				feed_id,
+				round,
+				round as u64 * 10
+			));
+		}
+
+		let feed = ChainlinkFeed::feed(feed_id).unwrap();
+		let range = |start, end| -> Vec<(RoundId, u64)> {
+			feed.data_range(start, end)
+				.into_iter()
+				.map(|(round, data)| (round, data.answer))
+				.collect()
+		};
+		// rounds 1 and 2 were pruned, round 0 is the seed round
+		assert_eq!(range(0, 10), vec![(3, 30), (4, 40), (5, 50)]);
+		assert_eq!(range(4, 4), vec![(4, 40)]);
+		assert_eq!(range(5, 4), vec![]);
+	});
+}
+
+#[test]
+fn answers_since_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new()
+			.min_submissions(1)
+			.restart_delay(0)
+			.build_and_store());
+		assert_eq!(
+			ChainlinkFeed::feed(feed_id).unwrap().answers_since(0),
+			vec![]
+		);
+		for round in 1..=5 {
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(2),
+				feed_id,
+				round,
+				round as u64 * 10
+			));
+		}
+
+		{
+			let feed = ChainlinkFeed::feed(feed_id).unwrap();
+			// round 0 is the seed round
+			assert_eq!(feed.answers_since(0), vec![10, 20, 30, 40, 50]);
+			assert_eq!(feed.answers_since(4), vec![40, 50]);
+			assert_eq!(feed.answers_since(6), vec![]);
+		}
+
+		// stops at the first missing round
+		Rounds::<Test>::remove(feed_id, 2);
+		let feed = ChainlinkFeed::feed(feed_id).unwrap();
+		assert_eq!(feed.answers_since(1), vec![30, 40, 50]);
+	});
+}
+
+#[test]
+fn pallet_admin_override_feed_owner_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let owner = 1;
+		let new_owner = 42;
+		let pallet_admin = ChainlinkFeed::pallet_admin();
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		assert_ok!(ChainlinkFeed::transfer_ownership(
+			Origin::signed(owner),
+			feed_id,
+			23
+		));
+
+		assert_noop!(
+			ChainlinkFeed::pallet_admin_override_feed_owner(
+				Origin::signed(owner),
+				feed_id,
+				new_owner
+			),
+			Error::<Test>::NotPalletAdmin
+		);
+		assert_noop!(
+			ChainlinkFeed::pallet_admin_override_feed_owner(
+				Origin::signed(pallet_admin),
+				123,
+				new_owner
+			),
+			Error::<Test>::FeedNotFound
+		);
+
+		assert_ok!(ChainlinkFeed::pallet_admin_override_feed_owner(
+			Origin::signed(pallet_admin),
+			feed_id,
+			new_owner
+		));
+		let config = ChainlinkFeed::feed_config(feed_id).unwrap();
+		assert_eq!(config.owner, new_owner);
+		assert_eq!(config.pending_owner, None);
+		let expected =
+			mock::Event::ChainlinkFeed(crate::Event::OwnerOverridden(feed_id, owner, new_owner));
+		assert!(System::events().iter().any(|e| e.event == expected));
+		// the new owner can manage the feed
+		assert_ok!(ChainlinkFeed::set_payment_amount(
+			Origin::signed(new_owner),
+			feed_id,
+			30
+		));
+	});
+}
+
+#[test]
+fn force_answer_should_work() {
+	TestExtBuilder::default()
+		.with_feed(FeedBuilder::new())
+		.build()
+		.execute_with(|| {
+			let feed_id = 0;
+			let admin = ChainlinkFeed::pallet_admin();
+			System::set_block_number(1);
+			// start round 1 without answering it
+			assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+
+			assert_noop!(
+				ChainlinkFeed::force_answer(Origin::signed(1), feed_id, 1, 100),
+				Error::<Test>::NotPalletAdmin
+			);
+			assert_noop!(
+				ChainlinkFeed::force_answer(Origin::signed(admin), 123, 1, 100),
+				Error::<Test>::FeedNotFound
+			);
+			assert_noop!(
+				ChainlinkFeed::force_answer(Origin::signed(admin), feed_id, 2, 100),
+				Error::<Test>::RoundNotFound
+			);
+			assert_noop!(
+				ChainlinkFeed::force_answer(Origin::signed(admin), feed_id, 0, 100),
+				Error::<Test>::InvalidRound
+			);
+
+			System::set_block_number(3);
+			assert_ok!(ChainlinkFeed::force_answer(
+				Origin::signed(admin),
+				feed_id,
+				1,
+				100
+			));
+			let feed = ChainlinkFeed::feed(feed_id).unwrap();
+			assert_eq!(feed.latest_round(), 1);
+			assert_eq!(feed.latest_answer(), Some(100));
+			assert_eq!(
+				feed.latest_data(),
+				RoundData {
+					round_id: 1,
+					started_at: 1,
+					answer: 100,
+					updated_at: 3,
+					answered_in_round: 1,
+				}
+			);
+			assert!(System::events().iter().any(|r| r.event
+				== mock::Event::ChainlinkFeed(crate::Event::AnswerUpdated(feed_id, 1, 100, 3))));
+			assert!(System::events().iter().any(|r| r.event
+				== mock::Event::ChainlinkFeed(crate::Event::ForcedAnswer(feed_id, 1, 100, admin))));
+			// the round is closed
+			assert_noop!(
+				ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 42),
+				Error::<Test>::RoundAlreadyClosed
+			);
+		});
+}
+
+#[test]
+fn force_set_pallet_admin_should_work() {
+	new_test_ext().execute_with(|| {
+		let admin = ChainlinkFeed::pallet_admin();
+		let new_admin = 42;
+		assert_ok!(ChainlinkFeed::transfer_pallet_admin(
+			Origin::signed(admin),
+			123
+		));
+
+		assert_noop!(
+			ChainlinkFeed::force_set_pallet_admin(Origin::signed(admin), new_admin),
+			frame_support::sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ChainlinkFeed::force_set_pallet_admin(
+			Origin::root(),
+			new_admin
+		));
+		assert_eq!(ChainlinkFeed::pallet_admin(), new_admin);
+		// the pending transfer was cancelled
+		assert_eq!(PendingPalletAdmin::<Test>::get(), None);
+		assert_noop!(
+			ChainlinkFeed::accept_pallet_admin(Origin::signed(123)),
+			Error::<Test>::NotPendingPalletAdmin
+		);
+	});
+}
+
+#[test]
+fn treasury_share_should_be_paid_on_submission() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		let payment = 20;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.payment(payment)
+			.min_submissions(1)
+			.build_and_store());
+		assert_noop!(
+			ChainlinkFeed::update_feed_config(
+				Origin::signed(owner),
+				feed_id,
+				FeedConfigUpdates {
+					treasury_percent: Some(101),
+					..Default::default()
+				}
+			),
+			Error::<Test>::TreasuryPercentTooHigh
+		);
+		assert_ok!(ChainlinkFeed::update_feed_config(
+			Origin::signed(owner),
+			feed_id,
+			FeedConfigUpdates {
+				treasury_percent: Some(33),
+				..Default::default()
+			}
+		));
+
+		let treasury = TreasuryAccount::get();
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+		// 33% of 20 is 6.6, which is rounded down
+		assert_eq!(Balances::free_balance(treasury), 6);
+		assert_eq!(ChainlinkFeed::oracle(2).unwrap().withdrawable, 14);
+		// only the oracle's part counts towards its earnings
+		assert_eq!(ChainlinkFeed::oracle_feed_earnings(feed_id, &2), Some(14));
+
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 42));
+		assert_eq!(Balances::free_balance(treasury), 12);
+		assert_eq!(ChainlinkFeed::oracle(3).unwrap().withdrawable, 14);
+	});
+}
+
+#[test]
+fn new_round_event_should_name_the_requester() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		let requester = 22;
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		assert_ok!(ChainlinkFeed::set_requester(
+			Origin::signed(owner),
+			feed_id,
+			requester,
+			1
+		));
+		let new_round = |round, initiator| {
+			mock::Event::ChainlinkFeed(crate::Event::NewRound(feed_id, round, initiator, 1))
+		};
+
+		System::set_block_number(1);
+		assert_ok!(ChainlinkFeed::request_new_round(
+			Origin::signed(requester),
+			feed_id
+		));
+		assert!(System::events()
+			.iter()
+			.any(|r| r.event == new_round(1, requester)));
+
+		// other pallets pass the initiator via `MutableFeedInterface`
+		let initiator = 42;
+		assert_ok!(ChainlinkFeed::force_answer(
+			Origin::signed(ChainlinkFeed::pallet_admin()),
+			feed_id,
+			1,
+			10
+		));
+		let mut feed = ChainlinkFeed::feed_mut(feed_id).unwrap();
+		tx_assert_ok!(feed.request_new_round(initiator));
+		assert!(System::events()
+			.iter()
+			.any(|r| r.event == new_round(2, initiator)));
+	});
+}
+
+#[test]
+fn close_round_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.min_submissions(2)
+			.build_and_store());
+		System::set_block_number(1);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 10));
+
+		assert_noop!(
+			ChainlinkFeed::close_round(Origin::signed(2), feed_id, 1),
+			Error::<Test>::NotFeedOwner
+		);
+		assert_noop!(
+			ChainlinkFeed::close_round(Origin::signed(owner), feed_id, 2),
+			Error::<Test>::RoundNotFound
+		);
+		assert_noop!(
+			ChainlinkFeed::close_round(Origin::signed(owner), feed_id, 1),
+			Error::<Test>::NotEnoughSubmissions
+		);
+
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 20));
+		System::set_block_number(2);
+		assert_ok!(ChainlinkFeed::close_round(
+			Origin::signed(owner),
+			feed_id,
+			1
+		));
+		let round = ChainlinkFeed::round(feed_id, 1).unwrap();
+		assert_eq!(round.answer, Some(15));
+		// the answer recorded when the minimum was reached is kept
+		assert_eq!(round.updated_at, Some(1));
+		assert_eq!(ChainlinkFeed::round_details(feed_id, 1), None);
+		assert!(System::events()
+			.iter()
+			.any(|r| r.event == mock::Event::ChainlinkFeed(crate::Event::RoundClosed(feed_id, 1))));
+		assert!(!System::events().iter().any(|r| r.event
+			== mock::Event::ChainlinkFeed(crate::Event::AnswerUpdated(feed_id, 1, 15, 2))));
+
+		// the third oracle cannot submit anymore
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(4), feed_id, 1, 30),
+			Error::<Test>::RoundAlreadyClosed
+		);
+		assert_noop!(
+			ChainlinkFeed::close_round(Origin::signed(owner), feed_id, 1),
+			Error::<Test>::RoundAlreadyAnswered
+		);
+	});
+}
+
+#[test]
+fn close_timed_out_round_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let (caller, other_caller) = (42, 43);
+		assert_ok!(FeedBuilder::new()
+			.timeout(1)
+			.restart_delay(0)
+			.build_and_store());
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 10));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 10));
+		// round 2 only receives a single submission
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 2, 20));
+
+		System::set_block_number(2);
+		assert_noop!(
+			ChainlinkFeed::close_timed_out_round(Origin::signed(caller), 123, 2),
+			Error::<Test>::FeedNotFound
+		);
+		assert_noop!(
+			ChainlinkFeed::close_timed_out_round(Origin::signed(caller), feed_id, 2),
+			Error::<Test>::RoundNotTimedOut
+		);
+
+		System::set_block_number(3);
+		assert_noop!(
+			ChainlinkFeed::close_timed_out_round(Origin::signed(caller), feed_id, 1),
+			Error::<Test>::RoundAlreadyAnswered
+		);
+		assert_ok!(ChainlinkFeed::close_timed_out_round(
+			Origin::signed(caller),
+			feed_id,
+			2
+		));
+		assert_eq!(ChainlinkFeed::round_details(feed_id, 2), None);
+		// the answer of the previous round is carried over
+		assert_eq!(ChainlinkFeed::round(feed_id, 2).unwrap().answer, Some(10));
+		let aborted = mock::Event::ChainlinkFeed(crate::Event::RoundAborted(feed_id, 2, 1));
+		assert!(System::events().iter().any(|e| e.event == aborted));
+		assert_eq!(Balances::free_balance(caller), TimeoutCleanupReward::get());
+		assert_noop!(
+			ChainlinkFeed::close_timed_out_round(Origin::signed(caller), feed_id, 2),
+			Error::<Test>::RoundAlreadyAnswered
+		);
+
+		// no reward if the fund would drop below the minimum reserve
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 3, 10));
+		System::set_block_number(5);
+		Balances::make_free_balance_be(&ChainlinkFeed::fund_account_id(), MIN_RESERVE + 1);
+		assert_ok!(ChainlinkFeed::close_timed_out_round(
+			Origin::signed(other_caller),
+			feed_id,
+			3
+		));
+		assert_eq!(ChainlinkFeed::round_details(feed_id, 3), None);
+		assert_eq!(Balances::free_balance(other_caller), 0);
+	});
+}
+
+#[test]
+fn round_submission_count_should_work() {
+	TestExtBuilder::default()
+		.with_feed(FeedBuilder::new())
+		.build()
+		.execute_with(|| {
+			let feed_id = 0;
+			assert_eq!(ChainlinkFeed::round_submission_count(feed_id, 1), None);
+
+			for (oracle, expected) in vec![(2, Some(1)), (3, Some(2)), (4, None)] {
+				assert_ok!(ChainlinkFeed::submit(
+					Origin::signed(oracle),
+					feed_id,
+					1,
+					42
+				));
+				// the details are dropped once the maximum is reached
+				assert_eq!(ChainlinkFeed::round_submission_count(feed_id, 1), expected);
+			}
+		});
+}
+
+#[test]
+fn answer_validator_should_reject_answers() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new().min_submissions(2).build_and_store());
+		MaxValidAnswer::set(&100);
+
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 150));
+		// the median of 150 and 120 is rejected
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 120),
+			frame_support::sp_runtime::DispatchError::Other("answer too high")
+		);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 40));
+		assert_eq!(ChainlinkFeed::round(feed_id, 1).unwrap().answer, Some(95));
+
+		MaxValidAnswer::set(&u64::MAX);
+	});
+}
+
+#[test]
+fn answer_handlers_can_be_combined() {
+	use crate::traits::OnAnswerHandler;
+
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let data = RoundData {
+			round_id: 1,
+			started_at: 1,
+			answer: 42,
+			updated_at: 2,
+			answered_in_round: 1,
+		};
+		System::set_block_number(1);
+		<(Test, (Test, ())) as OnAnswerHandler<Test>>::on_answer(feed_id, data.clone());
+
+		let expected = mock::Event::ChainlinkFeed(crate::Event::NewData(feed_id, data));
+		assert_eq!(
+			System::events()
+				.iter()
+				.filter(|r| r.event == expected)
+				.count(),
+			2
+		);
+	});
+}
+
+#[test]
+fn max_rounds_open_should_be_enforced() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.timeout(1)
+			.restart_delay(0)
+			.build_and_store());
+		assert_ok!(ChainlinkFeed::update_feed_config(
+			Origin::signed(owner),
+			feed_id,
+			FeedConfigUpdates {
+				max_rounds_open: Some(2),
+				..Default::default()
+			}
+		));
+		System::set_block_number(1);
+		// round 1 is answered, but keeps accepting the third submission
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 10));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 10));
+		// round 2 times out and is closed when round 3 starts
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 2, 10));
+		System::set_block_number(3);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 3, 10));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(4), feed_id, 3, 10));
+		assert!(ChainlinkFeed::round_details(feed_id, 1).is_some());
+		assert!(ChainlinkFeed::round_details(feed_id, 3).is_some());
+
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(2), feed_id, 4, 10),
+			Error::<Test>::TooManyOpenRounds
+		);
+
+		assert_ok!(ChainlinkFeed::update_feed_config(
+			Origin::signed(owner),
+			feed_id,
+			FeedConfigUpdates {
+				max_rounds_open: Some(0),
+				..Default::default()
+			}
+		));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 4, 10));
+	});
+}
+
+#[test]
+fn submission_deadline_should_be_enforced() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.timeout(10)
+			.restart_delay(0)
+			.build_and_store());
+		assert_ok!(ChainlinkFeed::update_feed_config(
+			Origin::signed(owner),
+			feed_id,
+			FeedConfigUpdates {
+				submission_deadline_offset: Some(Some(2)),
+				..Default::default()
+			}
+		));
+		System::set_block_number(1);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 10));
+		assert_eq!(
+			ChainlinkFeed::round_details(feed_id, 1).unwrap().deadline,
+			Some(3)
+		);
+		// the deadline block is still accepted
+		System::set_block_number(3);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 10));
+		System::set_block_number(4);
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(4), feed_id, 1, 10),
+			Error::<Test>::SubmissionDeadlineExceeded
+		);
+
+		// removing the offset only affects new rounds
+		assert_ok!(ChainlinkFeed::update_feed_config(
+			Origin::signed(owner),
+			feed_id,
+			FeedConfigUpdates {
+				submission_deadline_offset: Some(None),
+				..Default::default()
+			}
+		));
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(4), feed_id, 1, 10),
+			Error::<Test>::SubmissionDeadlineExceeded
+		);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 2, 10));
+		assert_eq!(
+			ChainlinkFeed::round_details(feed_id, 2).unwrap().deadline,
+			None
+		);
+	});
+}
+
+#[test]
+fn deprecated_feeds_should_reject_submissions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let owner = 1;
+		let pallet_admin = ChainlinkFeed::pallet_admin();
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		assert_noop!(
+			ChainlinkFeed::deprecate_feed(Origin::signed(2), feed_id),
+			Error::<Test>::NotFeedOwner
+		);
+		assert_ok!(ChainlinkFeed::deprecate_feed(
+			Origin::signed(owner),
+			feed_id
+		));
+		assert!(ChainlinkFeed::feed_config(feed_id).unwrap().deprecated);
+		let deprecated_event =
+			mock::Event::ChainlinkFeed(crate::Event::FeedDeprecated(feed_id, owner));
+		assert!(System::events().iter().any(|e| e.event == deprecated_event));
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42),
+			Error::<Test>::FeedDeprecated
+		);
+
+		// the owner and the pallet admin can revert it
+		assert_noop!(
+			ChainlinkFeed::undeprecate_feed(Origin::signed(2), feed_id),
+			Error::<Test>::NotFeedOwner
+		);
+		assert_ok!(ChainlinkFeed::undeprecate_feed(
+			Origin::signed(pallet_admin),
+			feed_id
+		));
+		let undeprecated_event =
+			mock::Event::ChainlinkFeed(crate::Event::FeedUndeprecated(feed_id, pallet_admin));
+		assert!(System::events()
+			.iter()
+			.any(|e| e.event == undeprecated_event));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+
+		assert_ok!(ChainlinkFeed::deprecate_feed(
+			Origin::signed(owner),
+			feed_id
+		));
+		assert_ok!(ChainlinkFeed::undeprecate_feed(
+			Origin::signed(owner),
+			feed_id
+		));
+		assert!(!ChainlinkFeed::feed_config(feed_id).unwrap().deprecated);
+	});
+}
+
+#[test]
+fn round_data_proof_should_verify() {
+	use crate::verification::{round_data_key, verify_round_data};
+
+	let feed_id = 0;
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		assert_ok!(FeedBuilder::new().min_submissions(1).build_and_store());
+		System::set_block_number(1);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+	});
+	ext.commit_all().unwrap();
+	let backend = ext.as_backend();
+	let root = *backend.root();
+	let proof =
+		sp_state_machine::prove_read(backend, &[round_data_key::<Test>(feed_id, 1)]).unwrap();
+
+	let data = verify_round_data::<Test>(root, proof.clone(), feed_id, 1).unwrap();
+	assert_eq!(data.answer, 42);
+	assert_eq!(data.answered_in_round, 1);
+	// the proof does not cover other rounds
+	assert_eq!(
+		verify_round_data::<Test>(root, proof.clone(), feed_id, 2),
+		None
+	);
+	// or other state roots
+	assert_eq!(
+		verify_round_data::<Test>(Default::default(), proof, feed_id, 1),
+		None
+	);
+}
+
+#[test]
+fn oracle_response_time_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new().timeout(10).build_and_store());
+		assert_eq!(ChainlinkFeed::oracle_response_time(feed_id, &2), None);
+
+		System::set_block_number(2);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+		System::set_block_number(5);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 42));
+
+		assert_eq!(
+			ChainlinkFeed::oracle_status(feed_id, 3)
+				.unwrap()
+				.last_reported_block,
+			Some(5)
+		);
+		assert_eq!(ChainlinkFeed::oracle_response_time(feed_id, &2), Some(0));
+		assert_eq!(ChainlinkFeed::oracle_response_time(feed_id, &3), Some(3));
+	});
+}
+
+#[test]
+fn cancel_pallet_admin_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		let admin = ChainlinkFeed::pallet_admin();
+		let new_admin = 23;
+		assert_noop!(
+			ChainlinkFeed::cancel_pallet_admin_transfer(Origin::signed(admin)),
+			Error::<Test>::NoPendingPalletAdmin
+		);
+		assert_ok!(ChainlinkFeed::transfer_pallet_admin(
+			Origin::signed(admin),
+			new_admin
+		));
+		assert_noop!(
+			ChainlinkFeed::cancel_pallet_admin_transfer(Origin::signed(new_admin)),
+			Error::<Test>::NotPalletAdmin
+		);
+
+		System::set_block_number(1);
+		assert_ok!(ChainlinkFeed::cancel_pallet_admin_transfer(Origin::signed(
+			admin
+		)));
+		assert_eq!(PendingPalletAdmin::<Test>::get(), None);
+		assert!(System::events().iter().any(|r| r.event
+			== mock::Event::ChainlinkFeed(crate::Event::PalletAdminTransferCancelled(new_admin))));
+		assert_noop!(
+			ChainlinkFeed::accept_pallet_admin(Origin::signed(new_admin)),
+			Error::<Test>::NotPendingPalletAdmin
+		);
+		assert_eq!(ChainlinkFeed::pallet_admin(), admin);
+	});
+}
+
+#[test]
+fn global_max_submission_value_should_be_enforced() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let owner = 1;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.value_bounds(1, 1_000)
+			.min_submissions(1)
+			.build_and_store());
+		MaxSubmissionValue::set(&500);
+
+		assert_noop!(
+			ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 501),
+			Error::<Test>::SubmissionExceedsGlobalMax
+		);
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 500));
+
+		assert_noop!(
+			FeedBuilder::new().value_bounds(1, 501).build_and_store(),
+			Error::<Test>::WrongBounds
+		);
+		assert_noop!(
+			ChainlinkFeed::update_feed_config(
+				Origin::signed(owner),
+				feed_id,
+				FeedConfigUpdates {
+					submission_value_bounds: Some((1, 501)),
+					..Default::default()
+				}
+			),
+			Error::<Test>::WrongBounds
+		);
+		assert_ok!(FeedBuilder::new().value_bounds(1, 500).build_and_store());
+
+		MaxSubmissionValue::set(&u64::MAX);
+	});
+}
+
+#[test]
+fn feeds_for_oracle_should_work() {
+	new_test_ext().execute_with(|| {
+		let owner = 1;
+		let oracle = 2;
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.oracles(vec![(oracle, 4), (5, 5)])
+			.restart_delay(0)
+			.min_submissions(1)
+			.build_and_store());
+		let mut feeds = ChainlinkFeed::feeds_for_oracle(&oracle);
+		feeds.sort();
+		assert_eq!(feeds, vec![0, 1]);
+		assert_eq!(ChainlinkFeed::feeds_for_oracle(&5), vec![1]);
+		assert_eq!(ChainlinkFeed::feeds_for_oracle(&42), vec![]);
+
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			0,
+			vec![oracle],
+			vec![],
+		));
+		assert_eq!(ChainlinkFeed::feeds_for_oracle(&oracle), vec![1]);
+
+		// enabling the oracle again does not duplicate the entry
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			0,
+			vec![],
+			vec![(oracle, 4)],
+		));
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			1,
+			vec![oracle],
+			vec![(oracle, 4)],
+		));
+		let mut feeds = ChainlinkFeed::feeds_for_oracle(&oracle);
+		feeds.sort();
+		assert_eq!(feeds, vec![0, 1]);
+		assert_ok!(ChainlinkFeed::check_storage_consistency());
+	});
+}
+
+#[test]
+fn feed_age_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		System::set_block_number(3);
+		assert_ok!(FeedBuilder::new().build_and_store());
+		assert_eq!(ChainlinkFeed::feed_config(feed_id).unwrap().created_at, 3);
+
+		System::set_block_number(10);
+		let feed = ChainlinkFeed::feed(feed_id).unwrap();
+		assert_eq!(feed.feed_age(), 7);
+	});
+}
+
+#[test]
+fn paginated_snapshots_should_work() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(FeedBuilder::new().build_and_store());
+		}
+		let ids = |start, count| -> Vec<u16> {
+			ChainlinkFeed::paginated_snapshots(start, count)
+				.into_iter()
+				.map(|s| s.config.id)
+				.collect()
+		};
+		assert_eq!(ids(0, 10), vec![0, 1, 2]);
+		assert_eq!(ids(1, 1), vec![1]);
+		assert_eq!(ids(3, 10), vec![]);
+		assert_eq!(ids(0, 0), vec![]);
+	});
+}
+
+#[test]
+fn unchanged_answers_should_emit_answer_unchanged() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let submit = |round: RoundId, value: u64| {
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(2),
+				feed_id,
+				round,
+				value
+			));
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(3),
+				feed_id,
+				round,
+				value
+			));
+		};
+		let answer_events = || -> Vec<_> {
+			System::events()
+				.into_iter()
+				.filter_map(|r| match r.event {
+					mock::Event::ChainlinkFeed(crate::Event::AnswerUpdated(
+						_,
+						round,
+						answer,
+						_,
+					)) => Some((round, answer, true)),
+					mock::Event::ChainlinkFeed(crate::Event::AnswerUnchanged(
+						_,
+						round,
+						answer,
+						_,
+					)) => Some((round, answer, false)),
+					_ => None,
+				})
+				.collect()
+		};
+		assert_ok!(FeedBuilder::new().restart_delay(0).build_and_store());
+
+		submit(1, 10);
+		submit(2, 10);
+		submit(3, 42);
+		assert_eq!(
+			answer_events(),
+			vec![(1, 10, true), (2, 10, false), (3, 42, true)]
+		);
+		assert_eq!(ChainlinkFeed::feed(feed_id).unwrap().latest_round(), 3);
+	});
+}
+
+#[test]
+fn identical_submissions_should_emit_collusion_warning() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let suspicions = || -> Vec<_> {
+			System::events()
+				.into_iter()
+				.filter_map(|r| match r.event {
+					mock::Event::ChainlinkFeed(crate::Event::OracleCollusionSuspected(
+						_,
+						round,
+						value,
+						count,
+					)) => Some((round, value, count)),
+					_ => None,
+				})
+				.collect()
+		};
+		assert_ok!(FeedBuilder::new().restart_delay(0).build_and_store());
+
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 10));
+		// a single submission does not answer the round
+		assert!(suspicions().is_empty());
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 10));
+		// not emitted again for a round that is already suspected
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(4), feed_id, 1, 10));
+		assert_eq!(suspicions(), vec![(1, 10, 2)]);
+
+		// differing submissions are fine
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 2, 10));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 2, 20));
+		assert_eq!(suspicions().len(), 1);
+		// the answer is still recorded
+		assert_eq!(ChainlinkFeed::feed(feed_id).unwrap().latest_round(), 2);
+	});
+}
+
+#[test]
+fn answer_alerts_should_be_emitted_once_per_round() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let owner = 1;
+		let oracles = [2, 3, 4];
+		// rounds are answered by two of the three oracles
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.min_submissions(2)
+			.restart_delay(0)
+			.oracles(oracles.iter().map(|o| (*o, 4)).collect())
+			.build_and_store());
+		assert_ok!(ChainlinkFeed::set_deviation_alert(
+			Origin::signed(owner),
+			feed_id,
+			10
+		));
+		let submit_all = |round: RoundId, value: u64| {
+			for oracle in oracles.iter() {
+				assert_ok!(ChainlinkFeed::submit(
+					Origin::signed(*oracle),
+					feed_id,
+					round,
+					value
+				));
+			}
+		};
+		let alerts = || -> Vec<_> {
+			System::events()
+				.into_iter()
+				.filter_map(|r| match r.event {
+					mock::Event::ChainlinkFeed(crate::Event::AnswerDeviationHigh(_, round, ..)) => {
+						Some(("deviation", round))
+					}
+					mock::Event::ChainlinkFeed(crate::Event::AnswerUnchanged(_, round, ..)) => {
+						Some(("unchanged", round))
+					}
+					mock::Event::ChainlinkFeed(crate::Event::OracleCollusionSuspected(
+						_,
+						round,
+						..,
+					)) => Some(("collusion", round)),
+					_ => None,
+				})
+				.collect()
+		};
+
+		submit_all(1, 100);
+		submit_all(2, 200);
+		submit_all(3, 200);
+		assert_eq!(
+			alerts(),
+			vec![
+				("collusion", 1),
+				("deviation", 2),
+				("collusion", 2),
+				("collusion", 3),
+				("unchanged", 3),
+			]
+		);
+	});
+}
+
+#[test]
+fn value_at_block_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let submit = |round: RoundId, value: u64| {
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(2),
+				feed_id,
+				round,
+				value
+			));
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(3),
+				feed_id,
+				round,
+				value
+			));
+		};
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 1), None);
+		assert_ok!(FeedBuilder::new()
+			.restart_delay(0)
+			.pruning_window(2)
+			.build_and_store());
+		// the seed round is not a valid answer
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 1), None);
+
+		System::set_block_number(2);
+		submit(1, 10);
+		System::set_block_number(4);
+		submit(2, 20);
+
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 1), None);
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 2), Some(10));
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 3), Some(10));
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 4), Some(20));
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 100), Some(20));
+
+		// round 1 is pruned
+		System::set_block_number(6);
+		submit(3, 30);
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 3), None);
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 5), Some(20));
+		assert_eq!(ChainlinkFeed::value_at_block(feed_id, 6), Some(30));
+	});
+}
+
+#[test]
+fn paginated_summaries_should_work() {
+	use sp_runtime::traits::Hash;
+
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(FeedBuilder::new()
+				.description(b"LINK".to_vec())
+				.build_and_store());
+		}
+		let feed_id = 1;
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), feed_id, 1, 42));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), feed_id, 1, 42));
+
+		let summaries = ChainlinkFeed::paginated_summaries(0, 10);
+		assert_eq!(
+			summaries.iter().map(|s| s.id).collect::<Vec<_>>(),
+			vec![0, 1, 2]
+		);
+		assert_eq!(
+			summaries[1],
+			FeedSummary {
+				id: feed_id,
+				description_hash: <Test as frame_system::Config>::Hashing::hash_of(
+					&b"LINK".to_vec()
+				),
+				latest_answer: Some(42),
+				updated_at: Some(System::block_number()),
+				decimals: 5,
+			}
+		);
+		assert_eq!(summaries[0].latest_answer, None);
+		assert_eq!(summaries[0].updated_at, None);
+		assert_eq!(
+			ChainlinkFeed::paginated_summaries(1, 1),
+			vec![summaries[1].clone()]
+		);
+		assert_eq!(ChainlinkFeed::paginated_summaries(3, 10), vec![]);
+	});
+}
+
+#[test]
+fn round_data_rpc_conversion_should_work() {
+	use crate::rpc::RoundDataRpc;
+	use sp_std::convert::TryFrom;
+
+	let data = RoundData {
+		round_id: 3,
+		started_at: 1u64,
+		answer: 42u64,
+		updated_at: 2,
+		answered_in_round: 3,
+	};
+	assert_eq!(
+		RoundDataRpc::try_from(data),
+		Ok(RoundDataRpc {
+			round_id: 3,
+			started_at: 1,
+			answer: 42,
+			updated_at: 2,
+			answered_in_round: 3,
+		})
+	);
+}
+
+#[test]
+fn validate_feed_config_should_work() {
+	use crate::builder::FeedConfigBuilder;
+
+	new_test_ext().execute_with(|| {
+		let (config, oracles) = FeedConfigBuilder::<Test>::new(1)
+			.value_bounds(1, 1_000)
+			.count_bounds(2, 3)
+			.restart_delay(2)
+			.oracles(vec![(2, 4), (3, 4), (5, 4)])
+			.build()
+			.expect("config should be valid");
+		let oracle_count = oracles.len() as u32;
+		assert_ok!(validate_feed_config::<Test>(&config, oracle_count));
+
+		let check = |update: fn(&mut FeedConfigOf<Test>), oracle_count: u32| {
+			let mut config = config.clone();
+			update(&mut config);
+			validate_feed_config::<Test>(&config, oracle_count)
+		};
+		assert_noop!(
+			check(|c| c.pruning_window = 0, oracle_count),
+			Error::<Test>::CannotPruneRoundZero
+		);
+		assert_noop!(
+			check(|_| {}, OracleLimit::get() + 1),
+			Error::<Test>::OraclesLimitExceeded
+		);
+		assert_noop!(
+			check(|c| c.submission_value_bounds = (10, 1), oracle_count),
+			Error::<Test>::WrongBounds
+		);
+		MaxSubmissionValue::set(&500);
+		assert_noop!(
+			check(|c| c.submission_value_bounds = (1, 501), oracle_count),
+			Error::<Test>::WrongBounds
+		);
+		MaxSubmissionValue::set(&u64::MAX);
+		assert_noop!(
+			check(|c| c.submission_count_bounds = (3, 2), oracle_count),
+			Error::<Test>::WrongBounds
+		);
+		assert_noop!(
+			check(|c| c.submission_count_bounds = (2, 4), oracle_count),
+			Error::<Test>::MaxExceededTotal
+		);
+		assert_noop!(
+			check(|c| c.restart_delay = 3, oracle_count),
+			Error::<Test>::DelayNotBelowCount
+		);
+		assert_noop!(
+			check(|c| c.submission_count_bounds = (0, 2), oracle_count),
+			Error::<Test>::ZeroMinSubmissions
+		);
+		// also rejected for feeds without oracles
+		assert_noop!(
+			check(
+				|c| {
+					c.submission_count_bounds = (0, 0);
+					c.restart_delay = 0;
+				},
+				0
+			),
+			Error::<Test>::ZeroMinSubmissions
+		);
+		assert_noop!(
+			check(|c| c.treasury_percent = 101, oracle_count),
+			Error::<Test>::TreasuryPercentTooHigh
+		);
+	});
+}
+
+#[test]
+fn lifetime_submissions_should_work() {
+	new_test_ext().execute_with(|| {
+		let feed_id = 0;
+		let (oracle_a, oracle_b) = (2, 3);
+		assert_ok!(FeedBuilder::new().restart_delay(0).build_and_store());
+		assert_eq!(
+			ChainlinkFeed::lifetime_submissions(feed_id, &oracle_a),
+			Some(0)
+		);
+		assert_eq!(ChainlinkFeed::lifetime_submissions(feed_id, &42), None);
+
+		for round in 1..=3 {
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(oracle_a),
+				feed_id,
+				round,
+				42
+			));
+			assert_ok!(ChainlinkFeed::submit(
+				Origin::signed(oracle_b),
+				feed_id,
+				round,
+				42
+			));
+		}
+		assert_ok!(ChainlinkFeed::submit(
+			Origin::signed(oracle_a),
+			feed_id,
+			4,
+			42
+		));
+		assert_eq!(
+			ChainlinkFeed::lifetime_submissions(feed_id, &oracle_a),
+			Some(4)
+		);
+		assert_eq!(
+			ChainlinkFeed::lifetime_submissions(feed_id, &oracle_b),
+			Some(3)
+		);
+
+		// the count is kept when the oracle is disabled
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(1),
+			feed_id,
+			vec![oracle_a],
+			vec![],
+		));
+		assert_eq!(
+			ChainlinkFeed::lifetime_submissions(feed_id, &oracle_a),
+			Some(4)
+		);
+	});
+}
+
+#[test]
+fn oracle_earnings_should_work() {
+	new_test_ext().execute_with(|| {
+		let payment = 20;
+		let (admin, other_admin) = (4, 6);
+		assert_ok!(FeedBuilder::new().payment(payment).build_and_store());
+		assert_ok!(FeedBuilder::new()
+			.payment(payment)
+			.oracles(vec![(2, admin), (5, other_admin)])
+			.restart_delay(0)
+			.min_submissions(1)
+			.build_and_store());
+		assert_eq!(ChainlinkFeed::oracle_earnings(&admin), 0);
+
+		for oracle in &[2, 3] {
+			assert_ok!(ChainlinkFeed::submit(Origin::signed(*oracle), 0, 1, 42));
+		}
+		for oracle in &[2, 5] {
+			assert_ok!(ChainlinkFeed::submit(Origin::signed(*oracle), 1, 1, 42));
+		}
+		assert_eq!(ChainlinkFeed::oracle_earnings(&admin), 3 * payment);
+		assert_eq!(ChainlinkFeed::oracle_earnings(&other_admin), payment);
+		assert_eq!(ChainlinkFeed::oracle_earnings(&42), 0);
+
+		let mut oracles = ChainlinkFeed::oracle_addresses_for_admin(&admin);
+		oracles.sort_unstable();
+		assert_eq!(oracles, vec![2, 3, 4]);
+		assert_eq!(
+			ChainlinkFeed::oracle_addresses_for_admin(&other_admin),
+			vec![5]
+		);
+		assert_eq!(ChainlinkFeed::oracle_addresses_for_admin(&42), vec![]);
+	});
+}
+
+#[test]
+fn add_duplicate_oracle_should_fail_or_deduplicate() {
+	new_test_ext().execute_with(|| {
+		let owner = 1;
+		let feed_id = 0;
+		assert_ok!(FeedBuilder::new().owner(owner).build_and_store());
+		assert_eq!(ChainlinkFeed::feed_config(feed_id).unwrap().oracle_count, 3);
+
+		// the second entry is rejected and the whole call is reverted
+		assert_noop!(
+			ChainlinkFeed::change_oracles(
+				Origin::signed(owner),
+				feed_id,
+				vec![],
+				vec![(5, 6), (5, 6)],
+			),
+			Error::<Test>::AlreadyEnabled
+		);
+		assert_noop!(
+			FeedBuilder::new()
+				.oracles(vec![(5, 6), (5, 6), (7, 6)])
+				.build_and_store(),
+			Error::<Test>::AlreadyEnabled
+		);
+
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![],
+			vec![(5, 6)],
+		));
+		assert_eq!(ChainlinkFeed::feed_config(feed_id).unwrap().oracle_count, 4);
+		assert_eq!(ChainlinkFeed::active_oracle_count(feed_id), 4);
+		assert_ok!(ChainlinkFeed::check_storage_consistency());
+	});
+}
+
+#[test]
+fn min_oracle_count_should_be_enforced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let owner = 1;
+		let feed_id = 0;
+		assert_noop!(
+			FeedBuilder::new().min_oracle_count(4).build_and_store(),
+			Error::<Test>::BelowMinimumOracleCount
+		);
+		assert_noop!(
+			FeedBuilder::new()
+				.min_submissions(3)
+				.min_oracle_count(2)
+				.build_and_store(),
+			Error::<Test>::WrongBounds
+		);
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.min_oracle_count(2)
+			.build_and_store());
+		assert_eq!(
+			ChainlinkFeed::feed_config(feed_id)
+				.unwrap()
+				.min_oracle_count,
+			2
+		);
+
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![2],
+			vec![],
+		));
+		assert_noop!(
+			ChainlinkFeed::change_oracles(Origin::signed(owner), feed_id, vec![3], vec![]),
+			Error::<Test>::BelowMinimumOracleCount
+		);
+		// replacing an oracle keeps the count
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![3],
+			vec![(5, 4)],
+		));
+
+		// the minimum submission count cannot exceed the minimum oracle count
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![],
+			vec![(6, 4)],
+		));
+		assert_noop!(
+			ChainlinkFeed::update_future_rounds(Origin::signed(owner), feed_id, 20, (3, 3), 1, 1),
+			Error::<Test>::WrongBounds
+		);
+
+		assert_noop!(
+			ChainlinkFeed::set_min_oracle_count(Origin::signed(2), feed_id, 1),
+			Error::<Test>::NotFeedOwner
+		);
+		assert_noop!(
+			ChainlinkFeed::set_min_oracle_count(Origin::signed(owner), feed_id, 4),
+			Error::<Test>::BelowMinimumOracleCount
+		);
+		assert_noop!(
+			ChainlinkFeed::set_min_oracle_count(Origin::signed(owner), feed_id, 1),
+			Error::<Test>::WrongBounds
+		);
+		assert_ok!(ChainlinkFeed::set_min_oracle_count(
+			Origin::signed(owner),
+			feed_id,
+			0
+		));
+		let set_event = mock::Event::ChainlinkFeed(crate::Event::MinOracleCountSet(feed_id, 0));
+		assert!(System::events().iter().any(|e| e.event == set_event));
+		assert_ok!(ChainlinkFeed::change_oracles(
+			Origin::signed(owner),
+			feed_id,
+			vec![4],
+			vec![],
+		));
+	});
+}
+
+#[test]
+fn all_feed_ids_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ChainlinkFeed::all_feed_ids().count(), 0);
+
+		let feeds = 3;
+		for _ in 0..feeds {
+			assert_ok!(FeedBuilder::new().build_and_store());
+		}
+		assert_eq!(
+			ChainlinkFeed::all_feed_ids().count() as u16,
+			FeedCounter::<Test>::get()
+		);
+		let mut ids: Vec<_> = ChainlinkFeed::all_feed_ids().collect();
+		ids.sort_unstable();
+		assert_eq!(ids, vec![0, 1, 2]);
+	});
+}
+
+#[test]
+fn on_initialize_should_start_the_first_round() {
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		// more feeds than `MaxInitializationsPerBlock`
+		for _ in 0..4 {
+			assert_ok!(FeedBuilder::new().timeout(10).build_and_store());
+		}
+		// started by an oracle before `on_initialize` runs
+		let answered = 3;
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), answered, 1, 42));
+		assert_eq!(ChainlinkFeed::uninitialized_feeds(), vec![0, 1, 2, 3]);
+
+		System::set_block_number(2);
+		ChainlinkFeed::on_initialize(2);
+		let started_by = <ChainlinkFeed as FeedOracle<Test>>::system_account();
+		assert_eq!(started_by, ChainlinkFeed::fund_account_id());
+		for feed_id in &[0, 1] {
+			assert_eq!(
+				ChainlinkFeed::feed_config(*feed_id)
+					.unwrap()
+					.reporting_round,
+				1
+			);
+			let new_round =
+				mock::Event::ChainlinkFeed(crate::Event::NewRound(*feed_id, 1, started_by, 2));
+			assert!(System::events().iter().any(|e| e.event == new_round));
+		}
+		assert_eq!(ChainlinkFeed::feed_config(2).unwrap().reporting_round, 0);
+		assert_eq!(ChainlinkFeed::uninitialized_feeds(), vec![2, 3]);
+
+		System::set_block_number(3);
+		ChainlinkFeed::on_initialize(3);
+		assert_eq!(ChainlinkFeed::feed_config(2).unwrap().reporting_round, 1);
+		assert_eq!(
+			ChainlinkFeed::round_details(answered, 1)
+				.unwrap()
+				.started_by,
+			2
+		);
+		assert!(ChainlinkFeed::uninitialized_feeds().is_empty());
+
+		// oracles submit to the started round
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(2), 0, 1, 42));
+		assert_ok!(ChainlinkFeed::submit(Origin::signed(3), 0, 1, 42));
+		assert_eq!(ChainlinkFeed::feed_config(0).unwrap().latest_round, 1);
+	});
+}
+
+#[test]
+fn on_finalize_should_warn_about_low_reserves() {
+	use frame_support::traits::{Currency, Hooks};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let fund = ChainlinkFeed::fund_account_id();
+		assert_eq!(ChainlinkFeed::reserve_balance(), 100 * MIN_RESERVE);
+		let is_reserve_low = |e: &mock::Event| {
+			matches!(
+				e,
+				mock::Event::ChainlinkFeed(crate::Event::ReserveLow(_, _))
+			)
+		};
+		ChainlinkFeed::on_finalize(1);
+		assert!(!System::events().iter().any(|e| is_reserve_low(&e.event)));
+
+		// exactly twice the minimum is not low, yet
+		Balances::make_free_balance_be(&fund, 2 * MIN_RESERVE);
+		ChainlinkFeed::on_finalize(1);
+		assert!(!System::events().iter().any(|e| is_reserve_low(&e.event)));
+
+		Balances::make_free_balance_be(&fund, 2 * MIN_RESERVE - 1);
+		assert_eq!(ChainlinkFeed::reserve_balance(), 2 * MIN_RESERVE - 1);
+		ChainlinkFeed::on_finalize(1);
+		let expected =
+			mock::Event::ChainlinkFeed(crate::Event::ReserveLow(2 * MIN_RESERVE - 1, MIN_RESERVE));
+		assert!(System::events().iter().any(|e| e.event == expected));
+		assert!(ChainlinkFeed::reserve_low_warned());
+
+		// only emitted when crossing the threshold
+		let warnings = || {
+			System::events()
+				.iter()
+				.filter(|e| is_reserve_low(&e.event))
+				.count()
+		};
+		ChainlinkFeed::on_finalize(2);
+		assert_eq!(warnings(), 1);
+
+		// topping up resets the warning
+		Balances::make_free_balance_be(&fund, 2 * MIN_RESERVE);
+		ChainlinkFeed::on_finalize(3);
+		assert!(!ChainlinkFeed::reserve_low_warned());
+		Balances::make_free_balance_be(&fund, MIN_RESERVE);
+		ChainlinkFeed::on_finalize(4);
+		assert_eq!(warnings(), 2);
+	});
+}
+
+#[test]
+fn tags_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feed_id = 0;
+		let owner = 1;
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.tags(vec![b"crypto".to_vec(), b"eth".to_vec()])
+			.build_and_store());
+		assert_ok!(FeedBuilder::new()
+			.owner(owner)
+			.tags(vec![b"crypto".to_vec()])
+			.build_and_store());
+		let tags: Vec<Vec<u8>> = ChainlinkFeed::feed_config(feed_id)
+			.unwrap()
+			.tags
+			.into_iter()
+			.map(|t| t.into_inner())
+			.collect();
+		assert_eq!(tags, vec![b"crypto".to_vec(), b"eth".to_vec()]);
+		let mut crypto = ChainlinkFeed::feeds_with_tag(b"crypto");
+		crypto.sort();
+		assert_eq!(crypto, vec![0, 1]);
+		assert_eq!(ChainlinkFeed::feeds_with_tag(b"eth"), vec![feed_id]);
+
+		assert_noop!(
+			FeedBuilder::new()
+				.tags(vec![
+					b"a".to_vec(),
+					b"b".to_vec(),
+					b"c".to_vec(),
+					b"d".to_vec()
+				])
+				.build_and_store(),
+			Error::<Test>::TooManyTags
+		);
+		assert_noop!(
+			ChainlinkFeed::set_tags(Origin::signed(owner), feed_id, vec![vec![1; 11]]),
+			Error::<Test>::TagTooLong
+		);
+		assert_noop!(
+			ChainlinkFeed::set_tags(Origin::signed(2), feed_id, vec![]),
+			Error::<Test>::NotFeedOwner
+		);
+
+		assert_ok!(ChainlinkFeed::set_tags(
+			Origin::signed(owner),
+			feed_id,
+			vec![b"defi".to_vec()]
+		));
+		assert_eq!(ChainlinkFeed::feed_config(feed_id).unwrap().tags.len(), 1);
+		assert_eq!(ChainlinkFeed::feeds_with_tag(b"crypto"), vec![1]);
+		assert!(ChainlinkFeed::feeds_with_tag(b"eth").is_empty());
+		assert_eq!(ChainlinkFeed::feeds_with_tag(b"defi"), vec![feed_id]);
+		let tags_set = mock::Event::ChainlinkFeed(crate::Event::TagsSet(feed_id));
+		assert!(System::events().iter().any(|e| e.event == tags_set));
+	});
+}
+
+mod proptests {
+	use super::*;
+	use proptest::prelude::*;
+
+	proptest! {
+		#[test]
+		fn submissions_uphold_round_invariants(
+			oracle_count in 2usize..10,
+			min_seed in any::<u32>(),
+			values in prop::collection::vec(1u64..=1_000, 10),
+			block in 1u64..1_000,
+			past_round in 0u32..2,
+			oracle_seed in any::<usize>(),
+		) {
+			new_test_ext().execute_with(|| {
+				let feed_id = 0;
+				let payment = 20;
+				let min_count = 1 + min_seed % oracle_count as u32;
+				let oracles: Vec<(AccountId, AccountId)> =
+					(0..oracle_count as u64).map(|o| (10 + o, 100)).collect();
+				System::set_block_number(block);
+				assert_ok!(FeedBuilder::new()
+					.payment(payment)
+					.min_submissions(min_count)
+					.restart_delay(0)
+					.oracles(oracles.clone())
+					.build_and_store());
+
+				for round_id in 1..=2 {
+					for (i, (oracle, _)) in oracles.iter().enumerate() {
+						let withdrawable = ChainlinkFeed::oracle(oracle).unwrap().withdrawable;
+						assert_ok!(ChainlinkFeed::submit(
+							Origin::signed(*oracle),
+							feed_id,
+							round_id,
+							values[i]
+						));
+						// (b) every accepted submission is paid exactly once
+						assert_eq!(
+							ChainlinkFeed::oracle(oracle).unwrap().withdrawable,
+							withdrawable + payment
+						);
+						// (a) the round is answered as soon as `min_count` submissions are in
+						let answer = ChainlinkFeed::round(feed_id, round_id).unwrap().answer;
+						assert_eq!(answer.is_some(), i + 1 >= min_count as usize);
+					}
+				}
+
+				// (c) rounds before the reporting round do not accept submissions
+				let (oracle, _) = oracles[oracle_seed % oracle_count];
+				assert!(
+					ChainlinkFeed::submit(Origin::signed(oracle), feed_id, past_round, values[0])
+						.is_err()
+				);
+			});
+		}
+	}
+}
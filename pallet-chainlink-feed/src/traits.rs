@@ -1,5 +1,7 @@
 //! Traits
-use crate::{Config, RoundData};
+use sp_runtime::DispatchResult;
+
+use crate::{Config, RoundData, RoundId};
 
 /// This implementation wille be as a callback when the round answer updates
 pub trait OnAnswerHandler<T: Config> {
@@ -11,3 +13,35 @@ impl<T: Config> OnAnswerHandler<T> for () {
 		// do_nothing
 	}
 }
+
+/// Calls both handlers in order, e.g. to notify several pallets.
+impl<T: Config, A: OnAnswerHandler<T>, B: OnAnswerHandler<T>> OnAnswerHandler<T> for (A, B) {
+	fn on_answer(feed: T::FeedId, new_data: RoundData<T::BlockNumber, T::Value>) {
+		A::on_answer(feed, new_data.clone());
+		B::on_answer(feed, new_data);
+	}
+}
+
+/// Sanity check for aggregated answers, e.g. to reject implausible jumps.
+///
+/// Called before a new answer is stored. Returning an error rejects the submission
+/// that completed the answer.
+pub trait AnswerValidator<T: Config> {
+	fn validate(feed: T::FeedId, round: RoundId, answer: T::Value) -> DispatchResult;
+}
+
+impl<T: Config> AnswerValidator<T> for () {
+	fn validate(_feed: T::FeedId, _round: RoundId, _answer: T::Value) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Mirrors the `AggregatorV3Interface` of the Ethereum contracts, e.g. for EVM bridges.
+pub trait ChainlinkAggregatorV3<FeedId> {
+	/// Return `(round_id, answer, started_at, updated_at, answered_in_round)` of the latest
+	/// round, with the times in Unix seconds.
+	///
+	/// Returns `None` if the feed does not exist, has no answer, yet, or the answer does not
+	/// fit into an `i128`.
+	fn latest_round_data(feed_id: FeedId) -> Option<(u128, i128, u64, u64, u128)>;
+}
@@ -1,5 +1,7 @@
+#[cfg(test)]
 use frame_support::storage::{with_transaction, TransactionOutcome};
 use sp_arithmetic::traits::BaseArithmetic;
+use sp_std::prelude::*;
 
 /// Execute the supplied function in a new storage transaction.
 ///
@@ -8,7 +10,9 @@ use sp_arithmetic::traits::BaseArithmetic;
 ///
 /// Transactions can be nested to any depth. Commits happen to the parent
 /// transaction.
-// TODO: remove after move to Substrate v3 (once the semantics of #[transactional] work as intended)
+///
+/// **Note:** Extrinsics use `#[transactional]`, this is only used to test them.
+#[cfg(test)]
 pub(crate) fn with_transaction_result<R, E>(f: impl FnOnce() -> Result<R, E>) -> Result<R, E> {
 	with_transaction(|| {
 		let res = f();
@@ -22,29 +26,142 @@ pub(crate) fn with_transaction_result<R, E>(f: impl FnOnce() -> Result<R, E>) ->
 
 /// Determine the median of a slice of values.
 ///
-/// **Warning:** Will panic if passed an empty slice.
-pub(crate) fn median<T: Copy + BaseArithmetic>(numbers: &mut [T]) -> T {
+/// The median of an even number of values is the mean of the two middle values
+/// rounded towards zero. Returns zero for an empty slice.
+pub fn median<T: Copy + BaseArithmetic>(numbers: &mut [T]) -> T {
+	if numbers.is_empty() {
+		return T::zero();
+	}
 	numbers.sort_unstable();
 
 	let mid = numbers.len() / 2;
 	if numbers.len() % 2 == 0 {
-		numbers[mid - 1].saturating_add(numbers[mid]) / 2.into()
+		let (lower, upper) = (numbers[mid - 1], numbers[mid]);
+		let zero = T::zero();
+		// `lower + upper` can only overflow if both have the same sign and
+		// `upper - lower` can only overflow if they have different signs
+		if lower < zero && upper >= zero {
+			(lower + upper) / 2.into()
+		} else if upper < zero {
+			upper - (upper - lower) / 2.into()
+		} else {
+			lower + (upper - lower) / 2.into()
+		}
 	} else {
 		numbers[mid]
 	}
 }
 
+/// Check whether `new` deviates from `old` by more than `percent` percent of `old`.
+pub(crate) fn exceeds_deviation<T: Copy + BaseArithmetic>(old: T, new: T, percent: u8) -> bool {
+	let deviation = if new > old { new - old } else { old - new };
+	deviation.saturating_mul(100u8.into()) > old.saturating_mul(percent.into())
+}
+
+/// Determine the arithmetic mean of a slice of values.
+///
+/// Returns `None` for an empty slice or if the sum overflows.
+pub(crate) fn compute_moving_average<T: BaseArithmetic>(values: &[T]) -> Option<T> {
+	let count = T::try_from(values.len()).ok()?;
+	let sum = values
+		.iter()
+		.try_fold(T::zero(), |acc, v| acc.checked_add(v))?;
+	sum.checked_div(&count)
+}
+
+/// Determine the population variance of a slice of values.
+///
+/// The mean is truncated to an integer before computing the squared deviations.
+/// Returns `None` for an empty slice or if an intermediate result overflows.
+pub(crate) fn variance<T: BaseArithmetic>(numbers: &[T]) -> Option<T> {
+	let mean = compute_moving_average(numbers)?;
+	let squared_deviations = numbers
+		.iter()
+		.map(|n| {
+			let deviation = if n > &mean {
+				n.checked_sub(&mean)?
+			} else {
+				mean.checked_sub(n)?
+			};
+			deviation.checked_mul(&deviation)
+		})
+		.collect::<Option<Vec<T>>>()?;
+	compute_moving_average(&squared_deviations)
+}
+
+/// Determine the standard deviation of a slice of values using the given
+/// square root function.
+///
+/// Returns `None` for an empty slice or if an intermediate result overflows.
+pub(crate) fn std_dev_with_sqrt<T, F>(numbers: &[T], sqrt: F) -> Option<T>
+where
+	T: BaseArithmetic,
+	F: FnOnce(T) -> T,
+{
+	variance(numbers).map(sqrt)
+}
+
+/// Determine the standard deviation of a slice of values (rounded down to an integer).
+///
+/// Returns `None` for an empty slice or if an intermediate result overflows.
+pub(crate) fn std_dev<T: BaseArithmetic>(numbers: &[T]) -> Option<T> {
+	std_dev_with_sqrt(numbers, |v| v.integer_sqrt())
+}
+
 #[test]
 fn median_works() {
 	let mut values = vec![4u32, 6, 2, 7];
 	assert_eq!(median(&mut values), 5);
 	let mut values = vec![4u32, 6, 2, 7, 9];
 	assert_eq!(median(&mut values), 6);
+	let mut values = vec![u32::MAX, u32::MAX - 2];
+	assert_eq!(median(&mut values), u32::MAX - 1);
+}
+
+#[test]
+fn median_of_signed_values_rounds_towards_zero() {
+	assert_eq!(median(&mut [-3i64, 0]), -1);
+	assert_eq!(median(&mut [-3i64, 4]), 0);
+	assert_eq!(median(&mut [-3i64, -2]), -2);
+	assert_eq!(median(&mut [-7i64, 1, -4, 9]), -1);
+	assert_eq!(median(&mut [-7i64, 1, -4]), -4);
+	assert_eq!(median(&mut [i64::MIN, i64::MAX]), 0);
+	assert_eq!(median(&mut [i64::MIN, i64::MIN + 2]), i64::MIN + 1);
+	assert_eq!(median(&mut [i64::MIN, i64::MIN + 1]), i64::MIN + 1);
+	assert_eq!(median(&mut [i64::MAX, i64::MAX - 1]), i64::MAX - 1);
 }
 
 #[test]
-#[should_panic]
-fn median_panics_on_empty_slice() {
+fn median_of_empty_slice_is_zero() {
 	let mut empty: Vec<u32> = Vec::new();
-	median(&mut empty);
+	assert_eq!(median(&mut empty), 0);
+}
+
+#[test]
+fn moving_average_works() {
+	assert_eq!(compute_moving_average(&[4u32, 6, 2, 8]), Some(5));
+	assert_eq!(compute_moving_average(&[3u32]), Some(3));
+	assert_eq!(compute_moving_average::<u32>(&[]), None);
+	assert_eq!(compute_moving_average(&[u8::MAX, 1]), None);
+}
+
+#[test]
+fn std_dev_works() {
+	let values = [2u32, 4, 4, 4, 5, 5, 7, 9];
+	assert_eq!(variance(&values), Some(4));
+	assert_eq!(std_dev(&values), Some(2));
+	assert_eq!(std_dev_with_sqrt(&values, |v| v * 10), Some(40));
+	assert_eq!(std_dev(&[7u32, 7, 7]), Some(0));
+	assert_eq!(std_dev::<u32>(&[]), None);
+	assert_eq!(std_dev(&[0u8, 200]), None);
+}
+
+#[test]
+fn exceeds_deviation_works() {
+	assert!(!exceeds_deviation(100u32, 110, 10));
+	assert!(exceeds_deviation(100u32, 111, 10));
+	assert!(!exceeds_deviation(100u32, 90, 10));
+	assert!(exceeds_deviation(100u32, 89, 10));
+	assert!(exceeds_deviation(0u32, 1, 10));
+	assert!(!exceeds_deviation(0u32, 0, 10));
 }
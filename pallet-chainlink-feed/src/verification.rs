@@ -0,0 +1,37 @@
+//! Verification of round data against a state root
+//!
+//! Allows light clients (or bridges) to check the data of a round without trusting the
+//! node that served it.
+//!
+//! **Note:** The runtime cannot create proofs of its own storage. Fetch the proof from a
+//! full node via the `state_getReadProof` RPC for the key returned by [`round_data_key`]
+//! and check it against the state root of the block with [`verify_round_data`].
+use codec::Decode;
+use sp_core::H256;
+use sp_runtime::traits::BlakeTwo256;
+use sp_std::{convert::TryFrom, prelude::*};
+use sp_trie::{read_trie_value, Layout, StorageProof};
+
+use crate::{Config, RoundDataOf, RoundId, RoundOf, Rounds};
+
+/// The storage key of the given round in the `Rounds` map.
+pub fn round_data_key<T: Config>(feed_id: T::FeedId, round_id: RoundId) -> Vec<u8> {
+	Rounds::<T>::hashed_key_for(feed_id, round_id)
+}
+
+/// Check `proof` against the state `root` and return the data of the given round.
+///
+/// Returns `None` if the proof is invalid or incomplete, the round is not in storage or
+/// it was not answered, yet.
+pub fn verify_round_data<T: Config>(
+	root: H256,
+	proof: StorageProof,
+	feed_id: T::FeedId,
+	round_id: RoundId,
+) -> Option<RoundDataOf<T>> {
+	let db = proof.into_memory_db::<BlakeTwo256>();
+	let key = round_data_key::<T>(feed_id, round_id);
+	let encoded = read_trie_value::<Layout<BlakeTwo256>, _>(&db, &root, &key).ok()??;
+	let round = RoundOf::<T>::decode(&mut &encoded[..]).ok()?;
+	RoundDataOf::<T>::try_from((round_id, round)).ok()
+}
@@ -7,7 +7,10 @@
 
 use std::sync::Arc;
 
-use node_template_runtime::{opaque::Block, AccountId, Balance, Index};
+use node_template_runtime::{
+	opaque::Block, AccountId, Balance, BlockNumber, FeedId, Hash, Index, Runtime, Value,
+};
+use pallet_chainlink_feed::FeedSnapshot;
 pub use sc_rpc_api::DenyUnsafe;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
@@ -32,9 +35,20 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_chainlink_feed_rpc::ChainlinkFeedRuntimeApi<
+		Block,
+		AccountId,
+		Balance,
+		FeedId,
+		BlockNumber,
+		Value,
+		Hash,
+		FeedSnapshot<Runtime>,
+	>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + 'static,
 {
+	use pallet_chainlink_feed_rpc::{ChainlinkFeed, ChainlinkFeedApi};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
 	use substrate_frame_rpc_system::{FullSystem, SystemApi};
 
@@ -55,6 +69,10 @@ where
 		client.clone(),
 	)));
 
+	io.extend_with(ChainlinkFeedApi::to_delegate(
+		ChainlinkFeed::<_, _, Runtime>::new(client.clone()),
+	));
+
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
 	// to call into the runtime.
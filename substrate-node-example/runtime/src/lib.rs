@@ -21,13 +21,14 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
 pub use sp_runtime::{Perbill, Permill};
 // A few exports that help ease life for downstream crates.
 use sp_runtime::traits::{
-	AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify,
+	AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor,
+	Verify,
 };
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
@@ -105,10 +106,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("node-template"),
 	impl_name: create_runtime_str!("node-template"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 };
 
 pub const MILLISECS_PER_BLOCK: u64 = 6000;
@@ -298,8 +299,19 @@ parameter_types! {
 	pub const FeedPalletId: PalletId = PalletId(*b"linkfeed");
 	pub const MinimumReserve: Balance = ExistentialDeposit::get() * 1000;
 	pub const StringLimit: u32 = 30;
+	pub const TagLimit: u32 = 16;
+	pub const MaxTags: u32 = 8;
 	pub const OracleCountLimit: u32 = 25;
 	pub const FeedLimit: FeedId = 100;
+	pub const StalenessThreshold: BlockNumber = 100;
+	pub const HistoryDepth: u32 = 10;
+	pub const MinRequesterDelay: RoundId = 1;
+	pub const MaxInitializationsPerBlock: u32 = 10;
+	pub const MaxSubmissionValue: Value = Value::MAX / 2;
+	pub TreasuryAccount: AccountId = PalletId(*b"py/trsry").into_account();
+	pub const TimeoutCleanupReward: Balance = ExistentialDeposit::get() * 10;
+	pub const ExpectedBlockTime: u64 = MILLISECS_PER_BLOCK;
+	pub const FeedUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
 }
 
 impl pallet_chainlink_feed::Config for Runtime {
@@ -310,9 +322,25 @@ impl pallet_chainlink_feed::Config for Runtime {
 	type PalletId = FeedPalletId;
 	type MinimumReserve = MinimumReserve;
 	type StringLimit = StringLimit;
+	type TagLimit = TagLimit;
+	type MaxTags = MaxTags;
 	type OracleCountLimit = OracleCountLimit;
 	type FeedLimit = FeedLimit;
+	type StalenessThreshold = StalenessThreshold;
+	type HistoryDepth = HistoryDepth;
+	type MinRequesterDelay = MinRequesterDelay;
+	type MaxInitializationsPerBlock = MaxInitializationsPerBlock;
+	type MaxSubmissionValue = MaxSubmissionValue;
+	type TreasuryAccount = TreasuryAccount;
+	type TimeoutCleanupReward = TimeoutCleanupReward;
+	type UnixTime = Timestamp;
+	type ExpectedBlockTime = ExpectedBlockTime;
+	type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
 	type OnAnswerHandler = ();
+	type AnswerValidator = ();
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+	type UnsignedPriority = FeedUnsignedPriority;
 	type WeightInfo = ChainlinkWeightInfo<Runtime>;
 }
 
@@ -349,7 +377,7 @@ construct_runtime!(
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage},
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>},
 		// Include the custom logic from the template pallet in the runtime.
-		ChainlinkFeed: pallet_chainlink_feed::{Pallet, Call, Config<T>, Storage, Event<T>},
+		ChainlinkFeed: pallet_chainlink_feed::{Pallet, Call, Config<T>, Storage, Event<T>, ValidateUnsigned},
 		TemplateModule: pallet_template::{Pallet, Call, Storage, Event<T>},
 
 		Example: example::{Pallet, Call, Storage},
@@ -516,6 +544,73 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_chainlink_feed_runtime_api::ChainlinkFeedApi<
+		Block,
+		AccountId,
+		Balance,
+		FeedId,
+		BlockNumber,
+		Value,
+		Hash,
+		pallet_chainlink_feed::FeedSnapshot<Runtime>,
+	> for Runtime {
+		fn paginated_rounds(
+			feed_id: FeedId,
+			start: RoundId,
+			count: u32,
+		) -> Vec<(RoundId, pallet_chainlink_feed::Round<BlockNumber, Value>)> {
+			ChainlinkFeed::paginated_rounds(feed_id, start, count)
+		}
+
+		fn feed_snapshot(feed_id: FeedId) -> Option<pallet_chainlink_feed::FeedSnapshot<Runtime>> {
+			ChainlinkFeed::snapshot(feed_id)
+		}
+
+		fn paginated_snapshots(
+			start: FeedId,
+			count: u32,
+		) -> Vec<pallet_chainlink_feed::FeedSnapshot<Runtime>> {
+			ChainlinkFeed::paginated_snapshots(start, count)
+		}
+
+		fn all_feed_summaries(
+			start: FeedId,
+			count: u32,
+		) -> Vec<pallet_chainlink_feed::FeedSummary<FeedId, Hash, Value, BlockNumber>> {
+			ChainlinkFeed::paginated_summaries(start, count)
+		}
+
+		fn feed_value_at_block(feed_id: FeedId, block: BlockNumber) -> Option<Value> {
+			ChainlinkFeed::value_at_block(feed_id, block)
+		}
+
+		fn oracle_statuses(
+			feed_id: FeedId,
+		) -> Vec<(AccountId, pallet_chainlink_feed::OracleStatus<BlockNumber, Value, Balance>)> {
+			ChainlinkFeed::oracle_statuses_for_feed(feed_id).collect()
+		}
+
+		fn round_submission_count(feed_id: FeedId, round_id: RoundId) -> Option<u32> {
+			ChainlinkFeed::round_submission_count(feed_id, round_id)
+		}
+
+		fn feeds_for_oracle(oracle: AccountId) -> Vec<FeedId> {
+			ChainlinkFeed::feeds_for_oracle(&oracle)
+		}
+
+		fn oracle_earnings(admin: AccountId) -> Balance {
+			ChainlinkFeed::oracle_earnings(&admin)
+		}
+
+		fn oracle_addresses_for_admin(admin: AccountId) -> Vec<AccountId> {
+			ChainlinkFeed::oracle_addresses_for_admin(&admin)
+		}
+
+		fn list_requesters(feed_id: FeedId) -> Vec<(AccountId, pallet_chainlink_feed::Requester)> {
+			ChainlinkFeed::list_requesters(feed_id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn dispatch_benchmark(
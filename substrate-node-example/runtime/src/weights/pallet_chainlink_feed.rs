@@ -23,6 +23,10 @@
 // --output
 // ./runtime/src/weights
 
+// NOTE: The functions marked as placeholders were written by hand instead of being
+// generated by the benchmark CLI. Rerun the command above to replace them with
+// measured weights.
+
 #![allow(unused_parens)]
 #![allow(unused_imports)]
 
@@ -32,14 +36,17 @@ use sp_std::marker::PhantomData;
 /// Weight functions for pallet_chainlink_feed.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_chainlink_feed::WeightInfo for WeightInfo<T> {
-	fn create_feed(o: u32) -> Weight {
+	// Placeholder: written by hand, not benchmarked.
+	fn create_feed(o: u32, t: u32) -> Weight {
 		(38_850_000 as Weight)
 			// Standard Error: 66_000
 			.saturating_add((15_164_000 as Weight).saturating_mul(o as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add((1_104_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(o as Weight)))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(t as Weight)))
 	}
 	fn transfer_ownership() -> Weight {
 		(21_000_000 as Weight)
@@ -58,17 +65,57 @@ impl<T: frame_system::Config> pallet_chainlink_feed::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(o as Weight)))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn prune_single_round() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_deviation_alert() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_min_oracle_count() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_tags(t: u32) -> Weight {
+		(14_210_000 as Weight)
+			.saturating_add((2_117_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(t as Weight)))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn deprecate_feed() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn undeprecate_feed() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
 	fn submit_opening_round_answers() -> Weight {
 		(90_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(6 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
 	fn submit_closing_answer(o: u32) -> Weight {
 		(72_296_000 as Weight)
 			// Standard Error: 18_000
 			.saturating_add((211_000 as Weight).saturating_mul(o as Weight))
-			.saturating_add(T::DbWeight::get().reads(7 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
 	fn change_oracles(d: u32, n: u32) -> Weight {
 		(0 as Weight)
@@ -88,6 +135,42 @@ impl<T: frame_system::Config> pallet_chainlink_feed::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_payment_amount() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_timeout() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn update_feed_config() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn set_round_payment() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn close_round() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn close_timed_out_round() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
 	fn set_requester() -> Weight {
 		(25_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
@@ -108,11 +191,27 @@ impl<T: frame_system::Config> pallet_chainlink_feed::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn withdraw_payment_all(o: u32) -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add((22_000_000 as Weight).saturating_mul(o as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(o as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(o as Weight)))
+	}
 	fn transfer_admin() -> Weight {
 		(19_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn transfer_admin_batch(t: u32) -> Weight {
+		(2_000_000 as Weight)
+			.saturating_add((18_000_000 as Weight).saturating_mul(t as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(t as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(t as Weight)))
+	}
 	fn accept_admin() -> Weight {
 		(19_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
@@ -128,6 +227,28 @@ impl<T: frame_system::Config> pallet_chainlink_feed::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn prune_all_expired(f: u32, r: u32) -> Weight {
+		(6_215_000 as Weight)
+			.saturating_add((2_614_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add((3_301_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(f as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn force_answer() -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn pallet_admin_override_feed_owner() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn transfer_pallet_admin() -> Weight {
 		(17_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
@@ -138,6 +259,16 @@ impl<T: frame_system::Config> pallet_chainlink_feed::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Placeholder: written by hand, not benchmarked.
+	fn cancel_pallet_admin_transfer() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Placeholder: written by hand, not benchmarked.
+	fn force_set_pallet_admin() -> Weight {
+		(14_000_000 as Weight).saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn set_feed_creator() -> Weight {
 		(17_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))